@@ -0,0 +1,152 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// The system allocator, counting every allocation made through it.
+pub struct CountingAllocator;
+
+/* Counted per thread, so allocations elsewhere (such as other tests) don't leak into a sample */
+thread_local! {
+    static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+}
+
+fn count_allocation() {
+    let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+}
+
+fn allocations() -> u64 {
+    ALLOCATIONS.with(Cell::get)
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count_allocation();
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        count_allocation();
+        System.alloc_zeroed(layout)
+    }
+
+    /* growing a Vec or String in place still counts, it is as much a trip to the allocator */
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        count_allocation();
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sample {
+    pub elapsed: Duration,
+    pub allocations: u64,
+}
+
+/// Runs `f` once, timing it and counting the allocations it makes. Its result is dropped
+/// outside of the measurement.
+pub fn measure<T>(f: impl FnOnce() -> T) -> Sample {
+    let allocations_before = allocations();
+    let start = Instant::now();
+    let result = black_box(f());
+    let elapsed = start.elapsed();
+    let allocations = allocations() - allocations_before;
+    drop(result);
+    Sample {
+        elapsed,
+        allocations,
+    }
+}
+
+/// The timings of one phase of a day (parsing or one of the parts) over several runs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Summary {
+    pub phase: &'static str,
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+    /// The fewest allocations made by any one run, which leaves out one-off warm up costs.
+    pub allocations: u64,
+}
+
+impl Summary {
+    pub fn new(phase: &'static str, samples: &[Sample]) -> Self {
+        assert!(!samples.is_empty(), "no samples taken for {phase}");
+        let mut times: Vec<Duration> = samples.iter().map(|sample| sample.elapsed).collect();
+        times.sort();
+        Self {
+            phase,
+            runs: samples.len(),
+            min: times[0],
+            median: times[times.len() / 2],
+            max: times[times.len() - 1],
+            allocations: samples
+                .iter()
+                .map(|sample| sample.allocations)
+                .min()
+                .unwrap(),
+        }
+    }
+}
+
+pub const CSV_HEADER: &str = "day,phase,runs,min_ns,median_ns,max_ns,allocations";
+
+pub fn csv_row(day: u8, summary: &Summary) -> String {
+    format!(
+        "{day},{},{},{},{},{},{}",
+        summary.phase,
+        summary.runs,
+        summary.min.as_nanos(),
+        summary.median.as_nanos(),
+        summary.max.as_nanos(),
+        summary.allocations
+    )
+}
+
+pub fn table_row(day: u8, summary: &Summary) -> String {
+    format!(
+        "{day:>3}  {:<6} {:>12.3?} {:>12.3?} {:>12.3?} {:>12}",
+        summary.phase, summary.min, summary.median, summary.max, summary.allocations
+    )
+}
+
+pub fn table_header() -> String {
+    format!(
+        "day  {:<6} {:>12} {:>12} {:>12} {:>12}",
+        "phase", "min", "median", "max", "allocations"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summary() {
+        let samples: Vec<Sample> = [5, 1, 9, 3, 7]
+            .into_iter()
+            .map(|ms| Sample {
+                elapsed: Duration::from_millis(ms),
+                allocations: 4,
+            })
+            .collect();
+        let summary = Summary::new("part1", &samples);
+        assert_eq!(summary.runs, 5);
+        assert_eq!(summary.min, Duration::from_millis(1));
+        assert_eq!(summary.median, Duration::from_millis(5));
+        assert_eq!(summary.max, Duration::from_millis(9));
+        assert_eq!(csv_row(3, &summary), "3,part1,5,1000000,5000000,9000000,4");
+    }
+
+    #[test]
+    fn test_counts_allocations() {
+        let sample = measure(|| (0..10).map(|i| vec![i; 3]).collect::<Vec<_>>());
+        assert!(sample.allocations >= 11, "{sample:?}");
+        assert_eq!(measure(|| 2 + 2).allocations, 0);
+    }
+}
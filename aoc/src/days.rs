@@ -0,0 +1,274 @@
+use crate::bench::{measure, Sample, Summary};
+use anyhow::{anyhow, bail, Result};
+use aoc_utils::{
+    render::{svg::Svg, terminal::Animation},
+    Solution,
+};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// Parses a puzzle input and answers the requested parts, or both when no part is given. Each
+/// part succeeds or fails on its own; only an input which can't be parsed fails them all.
+pub type Runner = fn(&str, Option<u8>) -> Result<Vec<(u8, Result<String>)>>;
+
+/// Times parsing and each part over the given number of runs.
+pub type Bencher = fn(&str, usize) -> Result<Vec<Summary>>;
+
+pub struct Day {
+    pub number: u8,
+    pub run: Runner,
+    pub bench: Bencher,
+}
+
+/* A day which still panics somewhere is reported like one which returned an error, so the
+other part and the other days get their turn */
+fn guarded<T, E>(f: impl FnOnce() -> Result<T, E>) -> Result<T>
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(result) => Ok(result?),
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .copied()
+                .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+                .unwrap_or("no message");
+            Err(anyhow!("panicked: {message}"))
+        }
+    }
+}
+
+fn solve<S: Solution>(input: &str, part: Option<u8>) -> Result<Vec<(u8, Result<String>)>> {
+    let input = guarded(|| S::parse(input))?;
+    let mut answers = vec![];
+    if part != Some(2) {
+        answers.push((1, guarded(|| S::part1(&input)).map(|a| a.to_string())));
+    }
+    if part != Some(1) {
+        answers.push((2, guarded(|| S::part2(&input)).map(|a| a.to_string())));
+    }
+    Ok(answers)
+}
+
+fn bench<S: Solution>(input: &str, runs: usize) -> Result<Vec<Summary>> {
+    /* there's no point timing a day which can't answer, so find out before the first run */
+    let parsed = S::parse(input)?;
+    S::part1(&parsed)?;
+    S::part2(&parsed)?;
+    let parse: Vec<Sample> = (0..runs).map(|_| measure(|| S::parse(input))).collect();
+    let input = parsed;
+    let part1: Vec<Sample> = (0..runs).map(|_| measure(|| S::part1(&input))).collect();
+    let part2: Vec<Sample> = (0..runs).map(|_| measure(|| S::part2(&input))).collect();
+    Ok(vec![
+        Summary::new("parse", &parse),
+        Summary::new("part1", &part1),
+        Summary::new("part2", &part2),
+    ])
+}
+
+pub const DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        run: solve::<day1::Day1>,
+        bench: bench::<day1::Day1>,
+    },
+    Day {
+        number: 2,
+        run: solve::<day2::Day2>,
+        bench: bench::<day2::Day2>,
+    },
+    Day {
+        number: 3,
+        run: solve::<day3::Day3>,
+        bench: bench::<day3::Day3>,
+    },
+    Day {
+        number: 4,
+        run: solve::<day4::Day4>,
+        bench: bench::<day4::Day4>,
+    },
+    Day {
+        number: 5,
+        run: solve::<day5::Day5>,
+        bench: bench::<day5::Day5>,
+    },
+    Day {
+        number: 6,
+        run: solve::<day6::Day6>,
+        bench: bench::<day6::Day6>,
+    },
+    Day {
+        number: 7,
+        run: solve::<day7::Day7>,
+        bench: bench::<day7::Day7>,
+    },
+    Day {
+        number: 8,
+        run: solve::<day8::Day8>,
+        bench: bench::<day8::Day8>,
+    },
+    Day {
+        number: 9,
+        run: solve::<day9::Day9>,
+        bench: bench::<day9::Day9>,
+    },
+    Day {
+        number: 10,
+        run: solve::<day10::Day10>,
+        bench: bench::<day10::Day10>,
+    },
+    Day {
+        number: 11,
+        run: solve::<day11::Day11>,
+        bench: bench::<day11::Day11>,
+    },
+    Day {
+        number: 12,
+        run: solve::<day12::Day12>,
+        bench: bench::<day12::Day12>,
+    },
+    Day {
+        number: 13,
+        run: solve::<day13::Day13>,
+        bench: bench::<day13::Day13>,
+    },
+    Day {
+        number: 14,
+        run: solve::<day14::Day14>,
+        bench: bench::<day14::Day14>,
+    },
+    Day {
+        number: 15,
+        run: solve::<day15::Day15>,
+        bench: bench::<day15::Day15>,
+    },
+    Day {
+        number: 16,
+        run: solve::<day16::Day16>,
+        bench: bench::<day16::Day16>,
+    },
+    Day {
+        number: 17,
+        run: solve::<day17::Day17>,
+        bench: bench::<day17::Day17>,
+    },
+    Day {
+        number: 18,
+        run: solve::<day18::Day18>,
+        bench: bench::<day18::Day18>,
+    },
+    Day {
+        number: 19,
+        run: solve::<day19::Day19>,
+        bench: bench::<day19::Day19>,
+    },
+    Day {
+        number: 20,
+        run: solve::<day20::Day20>,
+        bench: bench::<day20::Day20>,
+    },
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+/// Answers day 2 for a bag other than the puzzle's, given as a file or as its cubes. Only part
+/// 1 depends on the bag.
+pub fn solve_with_bag(
+    number: u8,
+    input: &str,
+    part: Option<u8>,
+    bag: &str,
+) -> Result<Vec<(u8, Result<String>)>> {
+    if number != 2 {
+        bail!("only day 2 is played with a bag");
+    }
+    let bag = day2::Bag::from_arg(bag)?;
+    let games = day2::Day2::parse(input)?;
+    let mut answers = vec![];
+    if part != Some(2) {
+        answers.push((1, Ok(day2::possible_games(&games, &bag).to_string())));
+    }
+    if part != Some(1) {
+        answers.push((2, Ok(day2::total_power(&games).to_string())));
+    }
+    Ok(answers)
+}
+
+/// Draws a day's puzzle as an SVG, for the days with a shape worth looking at. Days which
+/// differ between parts draw part 1 unless part 2 is asked for.
+pub fn render(number: u8, input: &str, part: Option<u8>) -> Result<Svg> {
+    let part = part.unwrap_or(1);
+    let svg = match number {
+        10 => day10::render_loop(&day10::Day10::parse(input)?)?,
+        16 => Some(day16::render_energized(&day16::Day16::parse(input)?)),
+        17 => Some(day17::render_route(&day17::Day17::parse(input)?, part)),
+        18 => Some(day18::render_lagoon(&day18::Day18::parse(input)?, part)?),
+        _ => bail!("day {number} has nothing to render"),
+    };
+    match svg {
+        Some(svg) => Ok(svg),
+        None => bail!("day {number} found nothing to draw in its input"),
+    }
+}
+
+/* Enough spin cycles to see every tilt direction a few times over */
+const ANIMATED_SPINS: usize = 3;
+
+/// Records a day's simulation for the terminal, for the days which have one to watch.
+pub fn animate(number: u8, input: &str, frame_rate: f64) -> Result<Animation> {
+    Ok(match number {
+        14 => day14::animate_spins(&day14::Day14::parse(input)?, ANIMATED_SPINS, frame_rate),
+        16 => day16::animate_beam(&day16::Day16::parse(input)?, frame_rate),
+        _ => bail!("day {number} has nothing to animate"),
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ReportFormat {
+    Text,
+    Csv,
+}
+
+/// Shows how a day read its input line by line, for the days which can explain themselves.
+/// Days which differ between parts report on part 1 unless part 2 is asked for. Day 2 checks
+/// its games against `bag`, or the puzzle's bag when none is given.
+pub fn report(
+    number: u8,
+    input: &str,
+    part: Option<u8>,
+    format: ReportFormat,
+    bag: Option<&str>,
+) -> Result<String> {
+    let part = part.unwrap_or(1);
+    if bag.is_some() && number != 2 {
+        bail!("only day 2 is played with a bag");
+    }
+    Ok(match number {
+        1 => {
+            let lines = day1::Day1::parse(input)?;
+            let vocabulary = match part {
+                1 => day1::Vocabulary::digits(),
+                _ => day1::Vocabulary::english(),
+            };
+            let report = day1::report(&lines, &vocabulary);
+            match format {
+                ReportFormat::Text => day1::report_text(&report),
+                ReportFormat::Csv => day1::report_csv(&report),
+            }
+        }
+        2 => {
+            let bag = match bag {
+                Some(arg) => day2::Bag::from_arg(arg)?,
+                None => day2::Bag::default(),
+            };
+            let stats = day2::game_stats(&day2::Day2::parse(input)?, &bag);
+            match format {
+                ReportFormat::Text => day2::explain(&stats, &bag),
+                ReportFormat::Csv => day2::explain_csv(&stats, &bag),
+            }
+        }
+        _ => bail!("day {number} has nothing to report"),
+    })
+}
@@ -0,0 +1,339 @@
+mod bench;
+mod days;
+
+use anyhow::{bail, Context, Result};
+use aoc_utils::answers::{self, Outcome};
+use aoc_utils::input::Source;
+use clap::{Parser, Subcommand};
+use std::path::{Path, PathBuf};
+
+#[global_allocator]
+static ALLOCATOR: bench::CountingAllocator = bench::CountingAllocator;
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a single day, or every day with --all
+    Run {
+        /// Day to solve
+        #[arg(
+            required_unless_present = "all",
+            value_parser = clap::value_parser!(u8).range(1..=25)
+        )]
+        day: Option<u8>,
+        /// Solve every available day, skipping days without an input
+        #[arg(long, conflicts_with_all = ["day", "input", "bag"])]
+        all: bool,
+        /// Only solve this part of the puzzle
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Puzzle input to use instead of dayN/input.txt, or - for stdin
+        #[arg(long)]
+        input: Option<String>,
+        /// Day 2's bag: a file listing its cubes, or the cubes themselves like "12 red, 13 green"
+        #[arg(long)]
+        bag: Option<String>,
+    },
+    /// Draw a day's puzzle as an SVG file (days 10, 16, 17 and 18)
+    Render {
+        /// Day to draw
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Draw this part's version of the puzzle, where the parts differ
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Puzzle input to use instead of dayN/input.txt, or - for stdin
+        #[arg(long)]
+        input: Option<String>,
+        /// Where to write the SVG, dayN.svg by default
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Time parsing and both parts of a day, or of every day with an input
+    Bench {
+        /// Day to time, every available day when left out
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// Puzzle input to use instead of dayN/input.txt, or - for stdin
+        #[arg(long, requires = "day")]
+        input: Option<String>,
+        /// How many times to run each phase
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        runs: u64,
+        /// Where to write the results as CSV
+        #[arg(long, default_value = "bench_results.csv")]
+        output: PathBuf,
+    },
+    /// Replay a day's simulation in the terminal (days 14 and 16)
+    Animate {
+        /// Day to animate
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Puzzle input to use instead of dayN/input.txt, or - for stdin
+        #[arg(long)]
+        input: Option<String>,
+        /// Frames per second
+        #[arg(long, default_value_t = 10.)]
+        fps: f64,
+        /// Print the first N frames as plain text instead of playing them
+        #[arg(long, value_name = "N")]
+        dump: Option<usize>,
+    },
+    /// Show how a day read its input, line by line (days 1 and 2)
+    Report {
+        /// Day to report on
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Report on this part's reading of the input, where the parts differ
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Puzzle input to use instead of dayN/input.txt, or - for stdin
+        #[arg(long)]
+        input: Option<String>,
+        /// Print a table or CSV
+        #[arg(long, value_enum, default_value_t = days::ReportFormat::Text)]
+        format: days::ReportFormat,
+        /// Day 2's bag: a file listing its cubes, or the cubes themselves like "12 red, 13 green"
+        #[arg(long)]
+        bag: Option<String>,
+    },
+    /// Check the answers in each day's answers.txt, skipping inputs that aren't there
+    Verify {
+        /// Day to check, every day when left out
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+    },
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Run {
+            day,
+            all,
+            part,
+            input,
+            bag,
+        } => {
+            if all {
+                run_all(part)
+            } else {
+                let day = day.expect("clap requires a day unless --all is given");
+                run_day(day, part, &input_source(day, input), bag.as_deref())
+            }
+        }
+        Command::Render {
+            day,
+            part,
+            input,
+            output,
+        } => {
+            let input = input_source(day, input).read_to_string()?;
+            let svg = days::render(day, &input, part)?;
+            let output = output.unwrap_or_else(|| PathBuf::from(format!("day{day}.svg")));
+            svg.save(&output)
+                .with_context(|| format!("unable to write {}", output.display()))?;
+            println!("day {day}: drawn to {}", output.display());
+            Ok(())
+        }
+        Command::Bench {
+            day,
+            input,
+            runs,
+            output,
+        } => bench_days(day, input, runs as usize, &output),
+        Command::Animate {
+            day,
+            input,
+            fps,
+            dump,
+        } => {
+            if fps.is_nan() || fps <= 0. {
+                bail!("the frame rate has to be positive, got {fps}");
+            }
+            let input = input_source(day, input).read_to_string()?;
+            let animation = days::animate(day, &input, fps)?;
+            match dump {
+                Some(frames) => print!("{}", animation.dump(frames)),
+                None => animation.play(&mut std::io::stdout().lock())?,
+            }
+            Ok(())
+        }
+        Command::Report {
+            day,
+            part,
+            input,
+            format,
+            bag,
+        } => {
+            let input = input_source(day, input).read_to_string()?;
+            print!(
+                "{}",
+                days::report(day, &input, part, format, bag.as_deref())?
+            );
+            Ok(())
+        }
+        Command::Verify { day } => verify_days(day),
+    }
+}
+
+fn input_source(day: u8, arg: Option<String>) -> Source {
+    match arg {
+        Some(arg) => Source::from_arg(&arg),
+        None => Source::file(default_input(day)),
+    }
+}
+
+fn default_input(day: u8) -> PathBuf {
+    PathBuf::from(format!("day{day}/input.txt"))
+}
+
+fn run_all(part: Option<u8>) -> Result<()> {
+    let mut failures = 0;
+    for day in days::DAYS {
+        let input = default_input(day.number);
+        if !input.exists() {
+            println!(
+                "day {}: skipped, no input at {}",
+                day.number,
+                input.display()
+            );
+            continue;
+        }
+        match run_day(day.number, part, &Source::file(input), None) {
+            Ok(()) => {}
+            Err(e) => {
+                eprintln!("day {}: {e}", day.number);
+                failures += 1;
+            }
+        }
+    }
+    if failures > 0 {
+        bail!("{failures} day(s) failed");
+    }
+    Ok(())
+}
+
+fn run_day(day: u8, part: Option<u8>, input: &Source, bag: Option<&str>) -> Result<()> {
+    let Some(solution) = days::find(day) else {
+        bail!("day {day} is not implemented");
+    };
+    let input = input.read_to_string()?;
+    let answers = match bag {
+        Some(bag) => days::solve_with_bag(day, &input, part, bag)?,
+        None => (solution.run)(&input, part)?,
+    };
+    let mut failures = 0;
+    for (part, answer) in answers {
+        match answer {
+            Ok(answer) => println!("day {day} part {part}: {answer}"),
+            Err(e) => {
+                eprintln!("day {day} part {part}: {e}");
+                failures += 1;
+            }
+        }
+    }
+    if failures > 0 {
+        bail!("{failures} part(s) failed");
+    }
+    Ok(())
+}
+
+fn selected_days(day: Option<u8>) -> Result<Vec<&'static days::Day>> {
+    Ok(match day {
+        Some(number) => match days::find(number) {
+            Some(day) => vec![day],
+            None => bail!("day {number} is not implemented"),
+        },
+        None => days::DAYS.iter().collect(),
+    })
+}
+
+fn bench_days(day: Option<u8>, input: Option<String>, runs: usize, output: &Path) -> Result<()> {
+    let days = selected_days(day)?;
+    let mut csv = vec![bench::CSV_HEADER.to_owned()];
+    println!("{}", bench::table_header());
+    for day in days {
+        let source = input_source(day.number, input.clone());
+        if let (None, Source::File(path)) = (&input, &source) {
+            if !path.exists() {
+                println!("{:>3}  skipped, no input at {}", day.number, path.display());
+                continue;
+            }
+        }
+        let text = source.read_to_string()?;
+        let summaries = (day.bench)(&text, runs).with_context(|| format!("day {}", day.number))?;
+        for summary in summaries {
+            println!("{}", bench::table_row(day.number, &summary));
+            csv.push(bench::csv_row(day.number, &summary));
+        }
+    }
+    std::fs::write(output, csv.join("\n") + "\n")
+        .with_context(|| format!("unable to write {}", output.display()))?;
+    println!("results written to {}", output.display());
+    Ok(())
+}
+
+fn verify_days(day: Option<u8>) -> Result<()> {
+    let (mut passed, mut failed, mut skipped) = (0, 0, 0);
+    println!(
+        "day  {:<20} part {:>20} {:>20}  result",
+        "input", "expected", "actual"
+    );
+    for day in selected_days(day)? {
+        let dir = PathBuf::from(format!("day{}", day.number));
+        let path = dir.join("answers.txt");
+        if !path.exists() {
+            println!(
+                "{:>3}  skipped, no answers at {}",
+                day.number,
+                path.display()
+            );
+            continue;
+        }
+        let expected = answers::load(&path)?;
+        let checks = answers::check(&dir, &expected, |input, part| {
+            (day.run)(input, Some(part))?.remove(0).1
+        })?;
+        for check in checks {
+            let (actual, result) = match &check.outcome {
+                Outcome::Pass => {
+                    passed += 1;
+                    (check.expected.answer.as_str(), "PASS")
+                }
+                Outcome::Fail { actual } => {
+                    failed += 1;
+                    (actual.as_str(), "FAIL")
+                }
+                Outcome::Error { message } => {
+                    failed += 1;
+                    eprintln!("day {} {}: {message}", day.number, check.expected.input);
+                    ("-", "ERROR")
+                }
+                Outcome::Skipped => {
+                    skipped += 1;
+                    ("-", "skipped")
+                }
+            };
+            println!(
+                "{:>3}  {:<20} {:>4} {:>20} {:>20}  {result}",
+                day.number,
+                check.expected.input,
+                check.expected.part,
+                check.expected.answer,
+                actual
+            );
+        }
+    }
+    println!("{passed} passed, {failed} failed, {skipped} skipped");
+    if failed > 0 {
+        bail!("{failed} of the answers did not match");
+    }
+    Ok(())
+}
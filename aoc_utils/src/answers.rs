@@ -0,0 +1,229 @@
+use crate::input::{InputError, Source};
+use crate::Solution;
+use std::fmt;
+use std::path::Path;
+
+/* Each day keeps its known answers in an answers.txt next to its inputs, one per line:
+
+    # input             part  answer
+    sample_input.txt    1     35
+    input.txt           2     15290096
+
+Blank lines and lines starting with # are skipped. */
+
+/// One known answer: what `part` should give for the input file `input`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub input: String,
+    pub part: u8,
+    pub answer: String,
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Read(InputError),
+    InvalidLine { line: usize, text: String },
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Read(error) => write!(f, "{error}"),
+            Self::InvalidLine { line, text } => write!(
+                f,
+                "line {line} should be an input file, a part (1 or 2) and an answer: {text:?}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Read(error) => Some(error),
+            Self::InvalidLine { .. } => None,
+        }
+    }
+}
+
+pub fn parse(text: &str) -> Result<Vec<Expected>, AnswersError> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+        .map(|(i, line)| {
+            let invalid = || AnswersError::InvalidLine {
+                line: i + 1,
+                text: line.to_owned(),
+            };
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let [input, part, answer] = fields[..] else {
+                return Err(invalid());
+            };
+            let part = part.parse().ok().filter(|p| matches!(p, 1 | 2));
+            Ok(Expected {
+                input: input.to_owned(),
+                part: part.ok_or_else(invalid)?,
+                answer: answer.to_owned(),
+            })
+        })
+        .collect()
+}
+
+pub fn load(path: impl AsRef<Path>) -> Result<Vec<Expected>, AnswersError> {
+    let text = Source::file(path)
+        .read_to_string()
+        .map_err(AnswersError::Read)?;
+    parse(&text)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Fail {
+        actual: String,
+    },
+    /// The solution gave an error instead of an answer.
+    Error {
+        message: String,
+    },
+    /// The input file isn't there, which is expected for puzzle inputs as they aren't checked in.
+    Skipped,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub expected: Expected,
+    pub outcome: Outcome,
+}
+
+/// Checks every known answer whose input can be found in `dir`. `solve` answers one part of
+/// the puzzle for the text of an input.
+pub fn check<E: fmt::Display>(
+    dir: impl AsRef<Path>,
+    expected: &[Expected],
+    mut solve: impl FnMut(&str, u8) -> Result<String, E>,
+) -> Result<Vec<Check>, InputError> {
+    let mut checks = vec![];
+    for expected in expected {
+        let path = dir.as_ref().join(&expected.input);
+        let outcome = if !path.exists() {
+            Outcome::Skipped
+        } else {
+            match solve(&Source::file(path).read_to_string()?, expected.part) {
+                Ok(actual) if actual == expected.answer => Outcome::Pass,
+                Ok(actual) => Outcome::Fail { actual },
+                Err(e) => Outcome::Error {
+                    message: e.to_string(),
+                },
+            }
+        };
+        checks.push(Check {
+            expected: expected.clone(),
+            outcome,
+        });
+    }
+    Ok(checks)
+}
+
+pub fn solve_part<S: Solution>(input: &str, part: u8) -> Result<String, S::Error> {
+    let input = S::parse(input)?;
+    Ok(match part {
+        1 => S::part1(&input)?.to_string(),
+        _ => S::part2(&input)?.to_string(),
+    })
+}
+
+/// For a day's tests: checks the answers file at `path` against the day's solution, panicking
+/// with every mismatch. Answers for missing inputs are skipped.
+pub fn assert_answers<S: Solution>(path: impl AsRef<Path>) {
+    let path = path.as_ref();
+    let expected = load(path).unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+    let dir = path.parent().unwrap_or(Path::new(""));
+    let checks = check(dir, &expected, solve_part::<S>).unwrap_or_else(|e| panic!("{e}"));
+    let mut failures = vec![];
+    for Check { expected, outcome } in checks {
+        match outcome {
+            Outcome::Pass => {}
+            Outcome::Skipped => eprintln!("skipped {}, it isn't there", expected.input),
+            Outcome::Fail { actual } => failures.push(format!(
+                "{} part {}: expected {}, got {actual}",
+                expected.input, expected.part, expected.answer
+            )),
+            Outcome::Error { message } => failures.push(format!(
+                "{} part {}: expected {}, got an error: {message}",
+                expected.input, expected.part, expected.answer
+            )),
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let text = "# input part answer\n\nsample.txt 1 35\n  input.txt\t2   15290096\n";
+        assert_eq!(
+            parse(text).unwrap(),
+            vec![
+                Expected {
+                    input: "sample.txt".to_owned(),
+                    part: 1,
+                    answer: "35".to_owned()
+                },
+                Expected {
+                    input: "input.txt".to_owned(),
+                    part: 2,
+                    answer: "15290096".to_owned()
+                },
+            ]
+        );
+        for broken in ["sample.txt 3 35", "sample.txt 1", "sample.txt one 35"] {
+            assert!(
+                matches!(
+                    parse(broken),
+                    Err(AnswersError::InvalidLine { line: 1, .. })
+                ),
+                "{broken}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        let dir = std::env::temp_dir().join(format!("aoc_answers_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("sample.txt"), "1 2 3").unwrap();
+        std::fs::write(dir.join("broken.txt"), "1 two 3").unwrap();
+        let expected =
+            parse("sample.txt 1 6\nsample.txt 2 7\nmissing.txt 1 0\nbroken.txt 1 6").unwrap();
+        let sum = |input: &str, part: u8| {
+            let total: u32 = input
+                .split(' ')
+                .map(str::parse::<u32>)
+                .sum::<Result<_, _>>()?;
+            Ok::<_, std::num::ParseIntError>((total * part as u32).to_string())
+        };
+        let outcomes: Vec<Outcome> = check(&dir, &expected, sum)
+            .unwrap()
+            .into_iter()
+            .map(|check| check.outcome)
+            .collect();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(
+            outcomes,
+            vec![
+                Outcome::Pass,
+                Outcome::Fail {
+                    actual: "12".to_owned()
+                },
+                Outcome::Skipped,
+                Outcome::Error {
+                    message: "invalid digit found in string".to_owned()
+                }
+            ]
+        );
+    }
+}
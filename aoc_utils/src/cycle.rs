@@ -0,0 +1,189 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+/// A sequence of states which, after `prefix_len` steps, repeats every `period` steps.
+/// Every state up to the first repetition is kept, so any later state is a lookup away.
+#[derive(Debug, Clone)]
+pub struct Cycle<S> {
+    pub prefix_len: usize,
+    pub period: usize,
+    states: Vec<S>,
+}
+
+impl<S> Cycle<S> {
+    /// The state after `n` steps from the initial one.
+    pub fn state_at(&self, n: usize) -> &S {
+        if n < self.states.len() {
+            &self.states[n]
+        } else {
+            &self.states[self.prefix_len + (n - self.prefix_len) % self.period]
+        }
+    }
+}
+
+/* Both finders step until a state repeats, so a sequence which never does will spin forever */
+
+/// Finds the cycle by remembering every state seen. Fast, at the cost of keeping every state.
+pub fn find_cycle<S, F>(initial: S, mut step: F) -> Cycle<S>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> S,
+{
+    find_cycle_in_place(initial, |state| *state = step(state))
+}
+
+/// The same as [`find_cycle`] for a `step` which updates the state in place, so the only copy
+/// made of each state is the one kept in the cycle.
+pub fn find_cycle_in_place<S, F>(initial: S, mut step: F) -> Cycle<S>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&mut S),
+{
+    /* states are looked up by their hash rather than used as keys, so each is only held once */
+    let mut seen: HashMap<u64, Vec<usize>> = HashMap::new();
+    let mut states: Vec<S> = vec![];
+    let mut state = initial;
+    loop {
+        let earlier = seen.entry(hash_of(&state)).or_default();
+        if let Some(&prefix_len) = earlier.iter().find(|&&i| states[i] == state) {
+            return Cycle {
+                prefix_len,
+                period: states.len() - prefix_len,
+                states,
+            };
+        }
+        earlier.push(states.len());
+        states.push(state.clone());
+        step(&mut state);
+    }
+}
+
+fn hash_of<S: Hash>(state: &S) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    state.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Finds the cycle with Brent's algorithm, which only ever holds two states while searching.
+/// The states are then replayed once to fill in the cycle.
+pub fn find_cycle_brent<S, F>(initial: S, mut step: F) -> Cycle<S>
+where
+    S: Clone + Eq,
+    F: FnMut(&S) -> S,
+{
+    /* search doubling windows for the period: the tortoise waits at the start of each window */
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    /* with the hare a period ahead, the two meet exactly where the cycle begins */
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+    let mut prefix_len = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix_len += 1;
+    }
+
+    let mut states = Vec::with_capacity(prefix_len + period);
+    let mut state = initial;
+    for _ in 1..prefix_len + period {
+        let next = step(&state);
+        states.push(state);
+        state = next;
+    }
+    states.push(state);
+    Cycle {
+        prefix_len,
+        period,
+        states,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /* 0 → 1 → 2 → 3 → 4 → 5 → 6 → 3 → ...: a prefix of 3 and a period of 4 */
+    fn rho(state: &u32) -> u32 {
+        if *state == 6 {
+            3
+        } else {
+            state + 1
+        }
+    }
+
+    fn square_plus_one(state: &u64) -> u64 {
+        (state * state + 1) % 1009
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let cycle = find_cycle(0, rho);
+        assert_eq!((cycle.prefix_len, cycle.period), (3, 4));
+        let cycle = find_cycle_brent(0, rho);
+        assert_eq!((cycle.prefix_len, cycle.period), (3, 4));
+        let cycle = find_cycle_in_place(0, |state| *state = rho(state));
+        assert_eq!((cycle.prefix_len, cycle.period), (3, 4));
+    }
+
+    #[test]
+    fn test_hash_collisions() {
+        /* every state hashes the same, so finding the repeat has to compare the states */
+        #[derive(Clone, PartialEq, Eq)]
+        struct Colliding(u32);
+        impl Hash for Colliding {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                0.hash(state);
+            }
+        }
+        let cycle = find_cycle(Colliding(0), |state| Colliding(rho(&state.0)));
+        assert_eq!((cycle.prefix_len, cycle.period), (3, 4));
+        assert_eq!(cycle.state_at(9).0, 5);
+    }
+
+    #[test]
+    fn test_pure_cycle() {
+        let cycle = find_cycle_brent(3, rho);
+        assert_eq!((cycle.prefix_len, cycle.period), (0, 4));
+        let cycle = find_cycle_brent(7, |_: &u32| 7);
+        assert_eq!((cycle.prefix_len, cycle.period), (0, 1));
+        assert_eq!(*cycle.state_at(1000), 7);
+    }
+
+    #[test]
+    fn test_variants_agree() {
+        for initial in [0, 2, 17, 500] {
+            let hashed = find_cycle(initial, square_plus_one);
+            let brent = find_cycle_brent(initial, square_plus_one);
+            assert_eq!(hashed.prefix_len, brent.prefix_len);
+            assert_eq!(hashed.period, brent.period);
+            assert_eq!(hashed.states, brent.states);
+        }
+    }
+
+    #[test]
+    fn test_state_at() {
+        let cycle = find_cycle(2, square_plus_one);
+        let mut state = 2;
+        for n in 0..3000 {
+            assert_eq!(*cycle.state_at(n), state, "state {n}");
+            state = square_plus_one(&state);
+        }
+    }
+}
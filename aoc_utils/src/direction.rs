@@ -0,0 +1,152 @@
+use crate::Point;
+use std::fmt;
+use std::str::FromStr;
+
+/// One of the four compass directions on a grid where rows grow downwards (south).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// All four directions, clockwise from north.
+    pub const ALL: [Direction; 4] = [Self::North, Self::East, Self::South, Self::West];
+
+    pub fn iter() -> impl Iterator<Item = Direction> {
+        Self::ALL.into_iter()
+    }
+
+    /// The offsets towards all eight surrounding cells, clockwise from north. Each diagonal is
+    /// made up of a direction and the one clockwise from it.
+    pub fn iter_with_diagonals() -> impl Iterator<Item = Point> {
+        Self::iter().flat_map(|direction| {
+            [
+                direction.offset(),
+                direction.offset() + direction.turn_right().offset(),
+            ]
+        })
+    }
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Self::North => Self::West,
+            Self::West => Self::South,
+            Self::South => Self::East,
+            Self::East => Self::North,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Self::North => Self::East,
+            Self::East => Self::South,
+            Self::South => Self::West,
+            Self::West => Self::North,
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    /// The step to take to move one cell this way.
+    pub fn offset(self) -> Point {
+        match self {
+            Self::North => Point::new(-1, 0),
+            Self::East => Point::new(0, 1),
+            Self::South => Point::new(1, 0),
+            Self::West => Point::new(0, -1),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDirectionError(String);
+
+impl fmt::Display for ParseDirectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unrecognised direction {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseDirectionError {}
+
+/* Puzzles spell directions as compass letters, as up/down/left/right, or draw them as arrows */
+impl FromStr for Direction {
+    type Err = ParseDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "n" | "u" | "^" | "↑" | "north" | "up" => Ok(Self::North),
+            "e" | "r" | ">" | "→" | "east" | "right" => Ok(Self::East),
+            "s" | "d" | "v" | "↓" | "south" | "down" => Ok(Self::South),
+            "w" | "l" | "<" | "←" | "west" | "left" => Ok(Self::West),
+            _ => Err(ParseDirectionError(s.to_owned())),
+        }
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = ParseDirectionError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        c.to_string().parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turning() {
+        for direction in Direction::iter() {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.opposite().opposite(), direction);
+            assert_ne!(direction.opposite(), direction);
+            let back = direction.offset() + direction.opposite().offset();
+            assert_eq!(back, Point::new(0, 0));
+        }
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+    }
+
+    #[test]
+    fn test_parsing() {
+        for text in ["N", "u", "^", "↑", "North", "UP"] {
+            assert_eq!(text.parse(), Ok(Direction::North), "{text}");
+        }
+        for text in ["E", "R", ">", "→", "east", "Right"] {
+            assert_eq!(text.parse(), Ok(Direction::East), "{text}");
+        }
+        assert_eq!(Direction::try_from('v'), Ok(Direction::South));
+        assert_eq!(Direction::try_from('L'), Ok(Direction::West));
+        assert_eq!(
+            "x".parse::<Direction>(),
+            Err(ParseDirectionError("x".to_owned()))
+        );
+    }
+
+    #[test]
+    fn test_eight_directions() {
+        let offsets: Vec<(i64, i64)> = Direction::iter_with_diagonals()
+            .map(|p| (p.row, p.col))
+            .collect();
+        assert_eq!(
+            offsets,
+            vec![
+                (-1, 0),
+                (-1, 1),
+                (0, 1),
+                (1, 1),
+                (1, 0),
+                (1, -1),
+                (0, -1),
+                (-1, -1)
+            ]
+        );
+    }
+}
@@ -0,0 +1,234 @@
+use crate::Point;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A rectangular grid stored row by row in a single `Vec`, addressed by `Point { row, col }`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        let mut cells = Vec::with_capacity(width * height);
+        for (i, row) in rows.into_iter().enumerate() {
+            assert_eq!(
+                row.len(),
+                width,
+                "row {i} has {} cells, expected {width}",
+                row.len()
+            );
+            cells.extend(row);
+        }
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Builds a grid from one line of text per row, turning every char into a cell with `f`.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> T) -> Self {
+        Self::from_rows(
+            input
+                .lines()
+                .map(|line| line.chars().map(&mut f).collect())
+                .collect(),
+        )
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.index_of(point).is_some()
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).map(|i| &mut self.cells[i])
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        /* chunks() refuses a zero chunk size, so an empty grid yields no rows */
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn col(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "column {col} out of bounds");
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.col(col))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// Every cell along with its location, in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| (self.point_of(i), cell))
+    }
+
+    pub fn find(&self, predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|i| self.point_of(i))
+    }
+
+    /// The in-bounds orthogonal neighbours of `point`, clockwise from north.
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.in_bounds(point.neighbors4())
+    }
+
+    /// The in-bounds orthogonal and diagonal neighbours of `point`, clockwise from north.
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.in_bounds(point.neighbors8())
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    fn in_bounds(&self, points: impl Iterator<Item = Point>) -> impl Iterator<Item = (Point, &T)> {
+        points.filter_map(move |point| self.get(point).map(|cell| (point, cell)))
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        let (row, col) = point.to_index(self.height, self.width)?;
+        Some(row * self.width + col)
+    }
+
+    fn point_of(&self, index: usize) -> Point {
+        Point::new((index / self.width) as i64, (index % self.width) as i64)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<char> {
+        Grid::parse("abc\ndef\n", |c| c)
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = sample();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[Point::new(1, 2)], 'f');
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+    }
+
+    #[test]
+    #[should_panic(expected = "row 1 has 2 cells, expected 3")]
+    fn test_ragged_rows() {
+        Grid::parse("abc\nde", |c| c);
+    }
+
+    #[test]
+    fn test_get_out_of_bounds() {
+        let grid = sample();
+        assert_eq!(grid.get(Point::new(0, 0)), Some(&'a'));
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+        assert_eq!(grid.get(Point::new(0, 3)), None);
+        assert_eq!(grid.get(Point::new(2, 0)), None);
+    }
+
+    #[test]
+    fn test_rows_and_cols() {
+        let grid = sample();
+        let rows: Vec<&[char]> = grid.rows().collect();
+        assert_eq!(rows, vec![&['a', 'b', 'c'][..], &['d', 'e', 'f'][..]]);
+        let cols: Vec<String> = grid.cols().map(|col| col.collect()).collect();
+        assert_eq!(cols, vec!["ad", "be", "cf"]);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = sample();
+        let corner: String = grid.neighbors4(Point::new(0, 0)).map(|(_, c)| *c).collect();
+        assert_eq!(corner, "bd");
+        let middle: String = grid.neighbors8(Point::new(0, 1)).map(|(_, c)| *c).collect();
+        assert_eq!(middle, "cfeda");
+    }
+
+    #[test]
+    fn test_find_and_positions() {
+        let grid = sample();
+        assert_eq!(grid.find(|c| *c == 'e'), Some(Point::new(1, 1)));
+        assert_eq!(grid.find(|c| *c == 'z'), None);
+        let (last, _) = grid.positions().last().unwrap();
+        assert_eq!(last, Point::new(1, 2));
+    }
+}
@@ -0,0 +1,156 @@
+use std::{
+    fmt,
+    fs::File,
+    io::{self, BufRead, BufReader, Cursor, Read},
+    path::{Path, PathBuf},
+};
+
+/// Where a puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+    Inline(String),
+}
+
+impl Source {
+    pub fn file(path: impl AsRef<Path>) -> Self {
+        Self::File(path.as_ref().to_path_buf())
+    }
+
+    /// A path argument, where `-` stands for stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            Self::Stdin
+        } else {
+            Self::file(arg)
+        }
+    }
+
+    /// The first command line argument, or `default_path` when none was given.
+    pub fn from_args(default_path: &str) -> Self {
+        match std::env::args().nth(1) {
+            Some(arg) => Self::from_arg(&arg),
+            None => Self::file(default_path),
+        }
+    }
+
+    pub fn reader(&self) -> Result<Box<dyn BufRead>, InputError> {
+        match self {
+            Self::File(path) => {
+                let file = File::open(path).map_err(|e| self.error(e))?;
+                Ok(Box::new(BufReader::new(file)))
+            }
+            Self::Stdin => Ok(Box::new(BufReader::new(io::stdin()))),
+            Self::Inline(text) => Ok(Box::new(Cursor::new(text.clone().into_bytes()))),
+        }
+    }
+
+    pub fn read_to_string(&self) -> Result<String, InputError> {
+        if let Self::Inline(text) = self {
+            return Ok(text.clone());
+        }
+        let mut input = String::new();
+        self.reader()?
+            .read_to_string(&mut input)
+            .map_err(|e| self.error(e))?;
+        Ok(input)
+    }
+
+    fn error(&self, error: io::Error) -> InputError {
+        InputError {
+            source: self.clone(),
+            error,
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::File(path) => write!(f, "{}", path.display()),
+            Self::Stdin => write!(f, "stdin"),
+            Self::Inline(_) => write!(f, "inline input"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    source: Source,
+    error: io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unable to read input from {}: {}",
+            self.source, self.error
+        )
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Reads the input named on the command line (or `default_path`) for a day's binary,
+/// exiting with the error message if it cannot be read.
+pub fn load_input(default_path: &str) -> String {
+    Source::from_args(default_path)
+        .read_to_string()
+        .unwrap_or_else(|e| {
+            eprintln!("{e}");
+            std::process::exit(1);
+        })
+}
+
+/// Reads a whole file, panicking with the error message if it cannot be read.
+pub fn read_file(path: impl AsRef<Path>) -> String {
+    Source::file(path)
+        .read_to_string()
+        .unwrap_or_else(|e| panic!("{e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inline_source() {
+        let source = Source::Inline("a\nb\n".to_owned());
+        assert_eq!(source.read_to_string().unwrap(), "a\nb\n");
+        let lines: Vec<String> = source
+            .reader()
+            .unwrap()
+            .lines()
+            .map(|l| l.unwrap())
+            .collect();
+        assert_eq!(lines, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_file_source() {
+        let input = Source::file("Cargo.toml").read_to_string().unwrap();
+        assert!(input.starts_with("[package]"));
+    }
+
+    #[test]
+    fn test_missing_file_reports_path() {
+        let error = Source::file("does_not_exist.txt")
+            .read_to_string()
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("unable to read input from does_not_exist.txt"));
+    }
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(Source::from_arg("-"), Source::Stdin);
+        assert_eq!(Source::from_arg("input.txt"), Source::file("input.txt"));
+    }
+}
@@ -1,92 +1,68 @@
-pub mod matrix {
-    pub type Matrix = Vec<Vec<char>>;
-
-    pub fn matrix_transpose(matrix: Matrix) -> Matrix {
-        let new_row: Vec<char> = vec!['x'; matrix.len()];
-        let mut new_matrix: Matrix = vec![new_row; matrix[0].len()];
-        matrix.iter().enumerate().for_each(|(i, row)| {
-            row.iter().enumerate().for_each(|(j, element)| {
-                new_matrix[j][i] = *element;
-            })
-        });
-        new_matrix
-    }
-
-    pub fn matrix_invert_rows(matrix: Matrix) -> Matrix {
-        let mut new_matrix = matrix.clone();
-        new_matrix.reverse();
-        new_matrix
-    }
-
-    pub fn matrix_invert_cols(matrix: Matrix) -> Matrix {
-        let mut new_matrix = matrix.clone();
-        new_matrix.iter_mut().for_each(|row| row.reverse());
-        new_matrix
-    }
-
-    pub fn matrix_rotate_ccw(matrix: Matrix) -> Matrix {
-        matrix_invert_rows(matrix_transpose(matrix))
-    }
-
-    pub fn matrix_rotate_cw(matrix: Matrix) -> Matrix {
-        matrix_invert_cols(matrix_transpose(matrix))
-    }
-}
-
-pub mod polygon {
-    pub type Polygon = Vec<Point>;
-
-    #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
-    pub struct Point {
-        pub row: i64,
-        pub col: i64,
-    }
-
-    impl Point {
-        pub fn new(row: i64, col: i64) -> Self {
-            Self { row, col }
-        }
-        pub fn add(&self, other: &Self) -> Self {
-            Self {
-                row: self.row + other.row,
-                col: self.col + other.col,
+pub mod solution {
+    use std::fmt::Display;
+
+    /// A single day's puzzle. The input is parsed once and both parts are answered from it.
+    pub trait Solution {
+        type Input;
+        type Answer: Display;
+        /// Why the input couldn't be parsed or a part couldn't be answered. Days which always
+        /// manage use [`std::convert::Infallible`].
+        type Error: std::error::Error + Send + Sync + 'static;
+
+        fn parse(input: &str) -> Result<Self::Input, Self::Error>;
+        fn part1(input: &Self::Input) -> Result<Self::Answer, Self::Error>;
+        fn part2(input: &Self::Input) -> Result<Self::Answer, Self::Error>;
+    }
+
+    /// For a day's binary: parses `text`, exiting with the error when it can't be.
+    pub fn parse_or_exit<S: Solution>(text: &str) -> S::Input {
+        S::parse(text).unwrap_or_else(|e| {
+            eprintln!("{e}");
+            std::process::exit(1);
+        })
+    }
+
+    /// For a day's binary: prints each part's answer, or why it has none. Both parts are tried
+    /// before exiting with a failure if either of them failed.
+    pub fn print_answers<S: Solution>(input: &S::Input) {
+        let mut failed = false;
+        for part in [1, 2] {
+            let answer = match part {
+                1 => S::part1(input),
+                _ => S::part2(input),
+            };
+            /* printed as soon as it's known, so part 1 is still seen when part 2 panics */
+            match answer {
+                Ok(answer) => println!("part {part}: {answer}"),
+                Err(e) => {
+                    eprintln!("part {part}: {e}");
+                    failed = true;
+                }
             }
         }
+        if failed {
+            std::process::exit(1);
+        }
     }
+}
 
-    pub fn circumference(polygon: &Polygon) -> f64 {
-        let mut circ = polygon
-            .windows(2)
-            .map(|coords| (coords[0].row, coords[1].row, coords[0].col, coords[1].col))
-            .map(|(x1, x2, y1, y2)| (((x1 - x2) * (x1 - x2) + (y1 - y2) * (y1 - y2)) as f64).sqrt())
-            .sum();
-        let last = polygon.last().unwrap();
-        let first = polygon.first().unwrap();
-        circ += (((first.row - last.row) * (first.row - last.row)
-            + (first.col - last.col) * (first.col - last.col)) as f64)
-            .sqrt();
-        circ
-    }
+pub use solution::Solution;
 
-    pub fn shoelace(polygon: &Polygon) -> f64 {
-        let mut polygon_copy = polygon.clone();
-        let start_coords = polygon[0];
-        polygon_copy.push(start_coords);
-        (polygon_copy
-            .windows(2)
-            .map(|coords| (coords[0].row, coords[0].col, coords[1].row, coords[1].col))
-            .map(|(y1, x1, y2, x2)| x1 * y2 - y1 * x2)
-            .sum::<i64>() as f64
-            * 0.5)
-            .abs()
-            .ceil()
-    }
+pub mod answers;
+pub mod csv;
+pub mod cycle;
+pub mod direction;
+pub mod grid;
+pub mod input;
+pub mod matrix;
+pub mod point;
+pub mod polygon;
+pub mod range_set;
+pub mod raster;
+pub mod render;
 
-    pub fn picks_theorem_num_internal_points(area: f64, num_boundary_points: i64) -> i64 {
-        println!("A = {area}, b = {num_boundary_points}");
-        (area + 1. - num_boundary_points as f64 * 0.5) as i64
-    }
-}
+pub use direction::Direction;
+pub use point::Point;
 
 #[cfg(test)]
 mod tests {
@@ -162,16 +138,54 @@ mod tests {
     }
     #[test]
     fn test_shoelace() {
-        let polygon: Vec<Point> = vec![
+        let polygon = Polygon::new(vec![
             Point { row: 1, col: 2 },
             Point { row: 3, col: 1 },
             Point { row: 2, col: 4 },
             Point { row: 4, col: 6 },
             Point { row: 0, col: 5 },
-        ];
+        ])
+        .unwrap();
         assert_eq!(shoelace(&polygon), 8.);
     }
 
+    #[test]
+    fn test_exact_area() {
+        let polygon = Polygon::new(vec![
+            Point { row: 1, col: 2 },
+            Point { row: 3, col: 1 },
+            Point { row: 2, col: 4 },
+            Point { row: 4, col: 6 },
+            Point { row: 0, col: 5 },
+        ])
+        .unwrap();
+        assert_eq!(twice_area(&polygon), 16);
+    }
+
+    #[test]
+    fn test_lattice_points() {
+        /* a right triangle with legs of 4 and 6: the hypotenuse passes through gcd(4, 6) = 2 steps */
+        let triangle =
+            Polygon::new(vec![Point::new(0, 0), Point::new(0, 6), Point::new(4, 0)]).unwrap();
+        assert_eq!(twice_area(&triangle), 24);
+        assert_eq!(boundary_lattice_points(&triangle), 12);
+        assert_eq!(interior_lattice_points(&triangle), 7);
+
+        /* far beyond where f64 can still tell neighbouring integers apart */
+        let big = 3_000_000_000;
+        let square = Polygon::new(vec![
+            Point::new(0, 0),
+            Point::new(0, big),
+            Point::new(big, big),
+            Point::new(big, 0),
+        ])
+        .unwrap();
+        let side = big as i128;
+        assert_eq!(twice_area(&square), 2 * side * side);
+        assert_eq!(boundary_lattice_points(&square), 4 * side);
+        assert_eq!(interior_lattice_points(&square), (side - 1) * (side - 1));
+    }
+
     #[test]
     fn test_picks() {
         assert_eq!(picks_theorem_num_internal_points(10., 8), 7)
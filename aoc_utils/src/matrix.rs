@@ -0,0 +1,268 @@
+pub type Matrix = Vec<Vec<char>>;
+
+pub fn matrix_transpose(matrix: Matrix) -> Matrix {
+    let new_row: Vec<char> = vec!['x'; matrix.len()];
+    let mut new_matrix: Matrix = vec![new_row; matrix[0].len()];
+    matrix.iter().enumerate().for_each(|(i, row)| {
+        row.iter().enumerate().for_each(|(j, element)| {
+            new_matrix[j][i] = *element;
+        })
+    });
+    new_matrix
+}
+
+pub fn matrix_invert_rows(mut matrix: Matrix) -> Matrix {
+    matrix_invert_rows_in_place(&mut matrix);
+    matrix
+}
+
+pub fn matrix_invert_cols(mut matrix: Matrix) -> Matrix {
+    matrix_invert_cols_in_place(&mut matrix);
+    matrix
+}
+
+pub fn matrix_rotate_ccw(matrix: Matrix) -> Matrix {
+    matrix_invert_rows(matrix_transpose(matrix))
+}
+
+pub fn matrix_rotate_cw(matrix: Matrix) -> Matrix {
+    matrix_invert_cols(matrix_transpose(matrix))
+}
+
+/* The in-place variants shuffle cells within the existing rows. Transposing (and so rotating)
+that way only works when the matrix is square, and they panic otherwise. */
+
+pub fn matrix_transpose_in_place<T>(matrix: &mut [Vec<T>]) {
+    let size = matrix.len();
+    for (i, row) in matrix.iter().enumerate() {
+        assert_eq!(
+            row.len(),
+            size,
+            "row {i} has {} cells, in-place transpose needs a square matrix",
+            row.len()
+        );
+    }
+    for j in 1..size {
+        let (above, below) = matrix.split_at_mut(j);
+        for (i, row) in above.iter_mut().enumerate() {
+            std::mem::swap(&mut row[j], &mut below[0][i]);
+        }
+    }
+}
+
+pub fn matrix_invert_rows_in_place<T>(matrix: &mut [Vec<T>]) {
+    matrix.reverse();
+}
+
+pub fn matrix_invert_cols_in_place<T>(matrix: &mut [Vec<T>]) {
+    matrix.iter_mut().for_each(|row| row.reverse());
+}
+
+pub fn matrix_rotate_ccw_in_place<T>(matrix: &mut [Vec<T>]) {
+    matrix_transpose_in_place(matrix);
+    matrix_invert_rows_in_place(matrix);
+}
+
+pub fn matrix_rotate_cw_in_place<T>(matrix: &mut [Vec<T>]) {
+    matrix_transpose_in_place(matrix);
+    matrix_invert_cols_in_place(matrix);
+}
+
+/// A transposed, flipped or rotated look at a matrix which translates indices on every access
+/// instead of copying cells around. Transforms can be chained and never touch the matrix.
+#[derive(Debug)]
+pub struct MatrixView<'a, T> {
+    matrix: &'a [Vec<T>],
+    transposed: bool,
+    rows_inverted: bool,
+    cols_inverted: bool,
+}
+
+/* derived impls would needlessly demand T: Copy, the view only holds a reference */
+impl<T> Clone for MatrixView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for MatrixView<'_, T> {}
+
+impl<'a, T> MatrixView<'a, T> {
+    pub fn new(matrix: &'a [Vec<T>]) -> Self {
+        Self {
+            matrix,
+            transposed: false,
+            rows_inverted: false,
+            cols_inverted: false,
+        }
+    }
+
+    pub fn num_rows(&self) -> usize {
+        if self.transposed {
+            self.source_cols()
+        } else {
+            self.matrix.len()
+        }
+    }
+
+    pub fn num_cols(&self) -> usize {
+        if self.transposed {
+            self.matrix.len()
+        } else {
+            self.source_cols()
+        }
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&'a T> {
+        if row >= self.num_rows() || col >= self.num_cols() {
+            return None;
+        }
+        let row = if self.rows_inverted {
+            self.num_rows() - 1 - row
+        } else {
+            row
+        };
+        let col = if self.cols_inverted {
+            self.num_cols() - 1 - col
+        } else {
+            col
+        };
+        let (row, col) = if self.transposed {
+            (col, row)
+        } else {
+            (row, col)
+        };
+        self.matrix[row].get(col)
+    }
+
+    pub fn row(self, row: usize) -> impl Iterator<Item = &'a T> {
+        assert!(row < self.num_rows(), "row {row} out of bounds");
+        (0..self.num_cols()).map(move |col| self.get(row, col).unwrap())
+    }
+
+    pub fn rows(self) -> impl Iterator<Item = impl Iterator<Item = &'a T>> {
+        (0..self.num_rows()).map(move |row| self.row(row))
+    }
+
+    pub fn transpose(self) -> Self {
+        /* a flip applied before transposing is the other axis' flip afterwards */
+        Self {
+            transposed: !self.transposed,
+            rows_inverted: self.cols_inverted,
+            cols_inverted: self.rows_inverted,
+            ..self
+        }
+    }
+
+    pub fn invert_rows(self) -> Self {
+        Self {
+            rows_inverted: !self.rows_inverted,
+            ..self
+        }
+    }
+
+    pub fn invert_cols(self) -> Self {
+        Self {
+            cols_inverted: !self.cols_inverted,
+            ..self
+        }
+    }
+
+    pub fn rotate_ccw(self) -> Self {
+        self.transpose().invert_rows()
+    }
+
+    pub fn rotate_cw(self) -> Self {
+        self.transpose().invert_cols()
+    }
+
+    pub fn to_matrix(&self) -> Vec<Vec<T>>
+    where
+        T: Clone,
+    {
+        self.rows().map(|row| row.cloned().collect()).collect()
+    }
+
+    fn source_cols(&self) -> usize {
+        self.matrix.first().map_or(0, Vec::len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rectangle() -> Matrix {
+        vec![vec!['a', 'b', 'c'], vec!['d', 'e', 'f']]
+    }
+
+    fn square() -> Matrix {
+        vec![
+            vec!['a', 'b', 'c'],
+            vec!['d', 'e', 'f'],
+            vec!['g', 'h', 'i'],
+        ]
+    }
+
+    #[test]
+    fn test_view_matches_owned_transforms() {
+        let matrix = rectangle();
+        let view = MatrixView::new(&matrix);
+        assert_eq!(view.to_matrix(), matrix);
+        assert_eq!(view.transpose().to_matrix(), matrix_transpose(rectangle()));
+        assert_eq!(
+            view.invert_rows().to_matrix(),
+            matrix_invert_rows(rectangle())
+        );
+        assert_eq!(
+            view.invert_cols().to_matrix(),
+            matrix_invert_cols(rectangle())
+        );
+        assert_eq!(
+            view.rotate_ccw().to_matrix(),
+            matrix_rotate_ccw(rectangle())
+        );
+        assert_eq!(view.rotate_cw().to_matrix(), matrix_rotate_cw(rectangle()));
+    }
+
+    #[test]
+    fn test_view_chains() {
+        let matrix = rectangle();
+        let view = MatrixView::new(&matrix);
+        assert_eq!(
+            view.rotate_cw().rotate_cw().to_matrix(),
+            matrix_invert_cols(matrix_invert_rows(rectangle()))
+        );
+        assert_eq!(view.rotate_cw().rotate_ccw().to_matrix(), matrix);
+        assert_eq!(
+            view.invert_rows().transpose().to_matrix(),
+            matrix_transpose(matrix_invert_rows(rectangle()))
+        );
+        let rotated = view.rotate_cw();
+        assert_eq!((rotated.num_rows(), rotated.num_cols()), (3, 2));
+        assert_eq!(rotated.get(0, 0), Some(&'d'));
+        assert_eq!(rotated.get(2, 1), Some(&'c'));
+        assert_eq!(rotated.get(3, 0), None);
+    }
+
+    #[test]
+    fn test_in_place_matches_owned_transforms() {
+        let mut matrix = square();
+        matrix_transpose_in_place(&mut matrix);
+        assert_eq!(matrix, matrix_transpose(square()));
+
+        let mut matrix = square();
+        matrix_rotate_cw_in_place(&mut matrix);
+        assert_eq!(matrix, matrix_rotate_cw(square()));
+
+        let mut matrix = square();
+        matrix_rotate_ccw_in_place(&mut matrix);
+        assert_eq!(matrix, matrix_rotate_ccw(square()));
+    }
+
+    #[test]
+    #[should_panic(expected = "in-place transpose needs a square matrix")]
+    fn test_in_place_transpose_rejects_rectangles() {
+        matrix_transpose_in_place(&mut rectangle());
+    }
+}
@@ -0,0 +1,155 @@
+use crate::Direction;
+use std::num::TryFromIntError;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A location on a grid, or the offset between two locations. Rows grow downwards.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub row: i64,
+    pub col: i64,
+}
+
+impl Point {
+    pub fn new(row: i64, col: i64) -> Self {
+        Self { row, col }
+    }
+
+    /// The number of orthogonal steps between two points.
+    pub fn manhattan_distance(self, other: Self) -> i64 {
+        (self.row - other.row).abs() + (self.col - other.col).abs()
+    }
+
+    /// The number of steps between two points when diagonal steps are allowed too.
+    pub fn chebyshev_distance(self, other: Self) -> i64 {
+        (self.row - other.row)
+            .abs()
+            .max((self.col - other.col).abs())
+    }
+
+    /// The four orthogonally adjacent points, clockwise from north.
+    pub fn neighbors4(self) -> impl Iterator<Item = Point> {
+        Direction::iter().map(move |direction| self + direction.offset())
+    }
+
+    /// All eight surrounding points, clockwise from north.
+    pub fn neighbors8(self) -> impl Iterator<Item = Point> {
+        Direction::iter_with_diagonals().map(move |offset| self + offset)
+    }
+
+    /// The `(row, col)` index of this point in a grid of the given size, if it lies inside it.
+    pub fn to_index(self, rows: usize, cols: usize) -> Option<(usize, usize)> {
+        let (row, col) = <(usize, usize)>::try_from(self).ok()?;
+        (row < rows && col < cols).then_some((row, col))
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.row + other.row, self.col + other.col)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.row - other.row, self.col - other.col)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Point {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.row, -self.col)
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Self;
+
+    fn mul(self, factor: i64) -> Self {
+        Self::new(self.row * factor, self.col * factor)
+    }
+}
+
+impl TryFrom<(usize, usize)> for Point {
+    type Error = TryFromIntError;
+
+    fn try_from((row, col): (usize, usize)) -> Result<Self, Self::Error> {
+        Ok(Self::new(i64::try_from(row)?, i64::try_from(col)?))
+    }
+}
+
+/* Fails for points with a negative coordinate, which can't index anything */
+impl TryFrom<Point> for (usize, usize) {
+    type Error = TryFromIntError;
+
+    fn try_from(point: Point) -> Result<Self, Self::Error> {
+        Ok((usize::try_from(point.row)?, usize::try_from(point.col)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Point::new(1, 2);
+        let b = Point::new(-3, 5);
+        assert_eq!(a + b, Point::new(-2, 7));
+        assert_eq!(a - b, Point::new(4, -3));
+        assert_eq!(-a, Point::new(-1, -2));
+        assert_eq!(b * 3, Point::new(-9, 15));
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point::new(6, 1);
+        let b = Point::new(11, 5);
+        assert_eq!(a.manhattan_distance(b), 9);
+        assert_eq!(b.manhattan_distance(a), 9);
+        assert_eq!(a.chebyshev_distance(b), 5);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let origin = Point::new(0, 0);
+        assert!(origin
+            .neighbors4()
+            .all(|p| p.manhattan_distance(origin) == 1));
+        assert_eq!(origin.neighbors8().count(), 8);
+        assert!(origin
+            .neighbors8()
+            .all(|p| p.chebyshev_distance(origin) == 1));
+    }
+
+    #[test]
+    fn test_index_conversions() {
+        assert_eq!(Point::try_from((3, 4)), Ok(Point::new(3, 4)));
+        assert_eq!(<(usize, usize)>::try_from(Point::new(3, 4)), Ok((3, 4)));
+        assert!(<(usize, usize)>::try_from(Point::new(-1, 4)).is_err());
+        assert_eq!(Point::new(2, 3).to_index(3, 4), Some((2, 3)));
+        assert_eq!(Point::new(3, 3).to_index(3, 4), None);
+        assert_eq!(Point::new(2, -1).to_index(3, 4), None);
+    }
+}
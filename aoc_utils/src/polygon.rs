@@ -0,0 +1,541 @@
+pub use crate::point::Point;
+use crate::Direction;
+use std::fmt;
+
+/// A closed polygon: the last vertex joins back up with the first. Construction checks that
+/// there is at least one vertex and, for rectilinear polygons, that no edges cross or touch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon {
+    vertices: Vec<Point>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PolygonError {
+    Empty,
+    NotClosed { start: Point, end: Point },
+    SelfIntersecting(Point),
+}
+
+impl fmt::Display for PolygonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "a polygon needs at least one vertex"),
+            Self::NotClosed { start, end } => write!(
+                f,
+                "the path starts at ({}, {}) but ends at ({}, {})",
+                start.row, start.col, end.row, end.col
+            ),
+            Self::SelfIntersecting(at) => {
+                write!(
+                    f,
+                    "the polygon runs into itself at ({}, {})",
+                    at.row, at.col
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for PolygonError {}
+
+/// Which way the vertices go round, as drawn with rows growing downwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    Clockwise,
+    CounterClockwise,
+    /* every vertex on a single line, so there is no way round */
+    Degenerate,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+/// The smallest axis-aligned box holding every vertex, both corners included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoundingBox {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Polygon {
+    /// A polygon through `vertices`, closed by an edge from the last vertex back to the first.
+    pub fn new(vertices: Vec<Point>) -> Result<Self, PolygonError> {
+        if vertices.is_empty() {
+            return Err(PolygonError::Empty);
+        }
+        let polygon = Self { vertices };
+        if polygon.is_rectilinear() {
+            polygon.check_rectilinear_simple()?;
+        }
+        Ok(polygon)
+    }
+
+    /// A polygon traced by a path, such as a dig plan, which has to end where it started.
+    pub fn from_closed_path(mut path: Vec<Point>) -> Result<Self, PolygonError> {
+        let (Some(&start), Some(&end)) = (path.first(), path.last()) else {
+            return Err(PolygonError::Empty);
+        };
+        if start != end {
+            return Err(PolygonError::NotClosed { start, end });
+        }
+        if path.len() > 1 {
+            path.pop();
+        }
+        Self::new(path)
+    }
+
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+
+    /// Every edge as a pair of its end points, including the one closing the polygon.
+    pub fn edges(&self) -> impl Iterator<Item = (Point, Point)> + '_ {
+        self.vertices
+            .iter()
+            .copied()
+            .zip(self.vertices.iter().copied().cycle().skip(1))
+    }
+
+    pub fn is_rectilinear(&self) -> bool {
+        self.edges().all(|(a, b)| a.row == b.row || a.col == b.col)
+    }
+
+    pub fn orientation(&self) -> Orientation {
+        match signed_twice_area(self).signum() {
+            1 => Orientation::Clockwise,
+            -1 => Orientation::CounterClockwise,
+            _ => Orientation::Degenerate,
+        }
+    }
+
+    pub fn bounding_box(&self) -> BoundingBox {
+        let first = self.vertices[0];
+        self.vertices.iter().fold(
+            BoundingBox {
+                min: first,
+                max: first,
+            },
+            |bounds, p| BoundingBox {
+                min: Point::new(bounds.min.row.min(p.row), bounds.min.col.min(p.col)),
+                max: Point::new(bounds.max.row.max(p.row), bounds.max.col.max(p.col)),
+            },
+        )
+    }
+
+    /// Where `point` lies relative to the polygon, found with an exact winding number.
+    pub fn contains(&self, point: Point) -> Location {
+        let mut winding = 0;
+        for (a, b) in self.edges() {
+            let side = cross(b - a, point - a);
+            if side == 0 && within(point, a, b) {
+                return Location::Boundary;
+            }
+            /* edges crossing the point's row to its left count +1 going up and -1 going down */
+            if a.row <= point.row {
+                if b.row > point.row && side < 0 {
+                    winding += 1;
+                }
+            } else if b.row <= point.row && side > 0 {
+                winding -= 1;
+            }
+        }
+        if winding == 0 {
+            Location::Outside
+        } else {
+            Location::Inside
+        }
+    }
+
+    /* Runs of collinear vertices are merged into single edges first, after which every pair of
+    edges that aren't neighbours must stay apart. Doubling back along an edge counts as running
+    into itself. */
+    fn check_rectilinear_simple(&self) -> Result<(), PolygonError> {
+        let mut vertices = self.vertices.clone();
+        vertices.dedup();
+        while vertices.len() > 1 && vertices.first() == vertices.last() {
+            vertices.pop();
+        }
+        let n = vertices.len();
+        if n < 3 {
+            return match n {
+                2 => Err(PolygonError::SelfIntersecting(vertices[1])),
+                _ => Ok(()),
+            };
+        }
+        let mut corners = vec![];
+        for i in 0..n {
+            let (prev, here, next) = (
+                vertices[(i + n - 1) % n],
+                vertices[i],
+                vertices[(i + 1) % n],
+            );
+            let (incoming, outgoing) = (here - prev, next - here);
+            if cross(incoming, outgoing) != 0 {
+                corners.push(here);
+            } else if dot(incoming, outgoing) < 0 {
+                return Err(PolygonError::SelfIntersecting(here));
+            }
+        }
+        let edges: Vec<(Point, Point)> = corners
+            .iter()
+            .copied()
+            .zip(corners.iter().copied().cycle().skip(1))
+            .collect();
+        for i in 0..edges.len() {
+            /* the last edge neighbours the first, so it is skipped when i is 0 */
+            let last = if i == 0 { edges.len() - 1 } else { edges.len() };
+            for j in i + 2..last {
+                if let Some(at) = axis_aligned_overlap(edges[i], edges[j]) {
+                    return Err(PolygonError::SelfIntersecting(at));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn cross(a: Point, b: Point) -> i128 {
+    a.col as i128 * b.row as i128 - a.row as i128 * b.col as i128
+}
+
+fn dot(a: Point, b: Point) -> i128 {
+    a.row as i128 * b.row as i128 + a.col as i128 * b.col as i128
+}
+
+fn within(point: Point, a: Point, b: Point) -> bool {
+    (a.row.min(b.row)..=a.row.max(b.row)).contains(&point.row)
+        && (a.col.min(b.col)..=a.col.max(b.col)).contains(&point.col)
+}
+
+/* Two axis-aligned segments meet exactly when their bounding boxes do */
+fn axis_aligned_overlap((a, b): (Point, Point), (c, d): (Point, Point)) -> Option<Point> {
+    let row = a.row.min(b.row).max(c.row.min(d.row));
+    let col = a.col.min(b.col).max(c.col.min(d.col));
+    let at = Point::new(row, col);
+    (within(at, a, b) && within(at, c, d)).then_some(at)
+}
+
+/// Traces a path one `(direction, steps)` move at a time, turtle style. Moves which carry on in
+/// the same direction extend the last edge instead of adding a vertex, so only corners are kept.
+#[derive(Debug, Clone)]
+pub struct PolygonBuilder {
+    vertices: Vec<Point>,
+    first_heading: Option<Direction>,
+    heading: Option<Direction>,
+    perimeter: i128,
+}
+
+impl PolygonBuilder {
+    pub fn new(start: Point) -> Self {
+        Self {
+            vertices: vec![start],
+            first_heading: None,
+            heading: None,
+            perimeter: 0,
+        }
+    }
+
+    /// Moves `steps` cells towards `direction`. A negative count moves the other way.
+    pub fn step(&mut self, direction: Direction, steps: i64) -> &mut Self {
+        let (direction, steps) = if steps < 0 {
+            (direction.opposite(), -steps)
+        } else {
+            (direction, steps)
+        };
+        if steps == 0 {
+            return self;
+        }
+        let position = self.position() + direction.offset() * steps;
+        if self.heading == Some(direction) {
+            *self.vertices.last_mut().unwrap() = position;
+        } else {
+            self.vertices.push(position);
+        }
+        self.first_heading.get_or_insert(direction);
+        self.heading = Some(direction);
+        self.perimeter += steps as i128;
+        self
+    }
+
+    pub fn position(&self) -> Point {
+        *self.vertices.last().unwrap()
+    }
+
+    /// The number of steps taken so far. Once the path is closed, this is also the number of
+    /// lattice points on the polygon's boundary.
+    pub fn perimeter(&self) -> i128 {
+        self.perimeter
+    }
+
+    /// The polygon enclosed by the path, which has to have come back to where it started.
+    pub fn build(&self) -> Result<Polygon, PolygonError> {
+        let mut polygon = Polygon::from_closed_path(self.vertices.clone())?;
+        /* the start is only a corner if the path turned there */
+        if polygon.vertices.len() > 1 && self.first_heading == self.heading {
+            polygon.vertices.remove(0);
+        }
+        Ok(polygon)
+    }
+
+    /// Every cell the path passes through, in order. The start is only listed once, even once
+    /// the path has come back round to it.
+    pub fn trench(&self) -> Vec<Point> {
+        let mut cells = vec![self.vertices[0]];
+        for pair in self.vertices.windows(2) {
+            let (from, to) = (pair[0], pair[1]);
+            let unit = Point::new((to.row - from.row).signum(), (to.col - from.col).signum());
+            let mut cell = from;
+            while cell != to {
+                cell += unit;
+                cells.push(cell);
+            }
+        }
+        if cells.len() > 1 && cells.first() == cells.last() {
+            cells.pop();
+        }
+        cells
+    }
+}
+
+impl Extend<(Direction, i64)> for PolygonBuilder {
+    fn extend<I: IntoIterator<Item = (Direction, i64)>>(&mut self, moves: I) {
+        for (direction, steps) in moves {
+            self.step(direction, steps);
+        }
+    }
+}
+
+pub fn circumference(polygon: &Polygon) -> f64 {
+    polygon
+        .edges()
+        .map(|(a, b)| (((a.row - b.row).pow(2) + (a.col - b.col).pow(2)) as f64).sqrt())
+        .sum()
+}
+
+pub fn shoelace(polygon: &Polygon) -> f64 {
+    (signed_twice_area(polygon) as f64 * 0.5).abs().ceil()
+}
+
+pub fn picks_theorem_num_internal_points(area: f64, num_boundary_points: i64) -> i64 {
+    (area + 1. - num_boundary_points as f64 * 0.5) as i64
+}
+
+/* The exact versions below stay in integers throughout, the float ones above lose precision
+once areas get anywhere near 2^53. */
+
+fn gcd(a: i128, b: i128) -> i128 {
+    if b == 0 {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn signed_twice_area(polygon: &Polygon) -> i128 {
+    polygon.edges().map(|(a, b)| cross(a, b)).sum()
+}
+
+/// Twice the polygon's area, which is always a whole number for integer vertices.
+pub fn twice_area(polygon: &Polygon) -> i128 {
+    signed_twice_area(polygon).abs()
+}
+
+/// The number of lattice points lying on the polygon's edges, vertices included.
+pub fn boundary_lattice_points(polygon: &Polygon) -> i128 {
+    polygon
+        .edges()
+        .map(|(a, b)| gcd((b.row - a.row) as i128, (b.col - a.col) as i128))
+        .sum()
+}
+
+/// The number of lattice points strictly inside the polygon, from Pick's theorem A = i + b/2 - 1.
+/// A polygon with no area has nothing inside it, which Pick's theorem doesn't cover.
+pub fn interior_lattice_points(polygon: &Polygon) -> i128 {
+    if polygon.orientation() == Orientation::Degenerate {
+        return 0;
+    }
+    (twice_area(polygon) - boundary_lattice_points(polygon) + 2) / 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points(coords: &[(i64, i64)]) -> Vec<Point> {
+        coords
+            .iter()
+            .map(|&(row, col)| Point::new(row, col))
+            .collect()
+    }
+
+    /* an L shape, drawn clockwise on screen */
+    fn l_shape() -> Polygon {
+        Polygon::new(points(&[(0, 0), (0, 2), (2, 2), (2, 4), (4, 4), (4, 0)])).unwrap()
+    }
+
+    #[test]
+    fn test_validation() {
+        assert_eq!(Polygon::new(vec![]), Err(PolygonError::Empty));
+        assert_eq!(Polygon::from_closed_path(vec![]), Err(PolygonError::Empty));
+        assert_eq!(
+            Polygon::from_closed_path(points(&[(0, 0), (0, 3), (2, 3)])),
+            Err(PolygonError::NotClosed {
+                start: Point::new(0, 0),
+                end: Point::new(2, 3)
+            })
+        );
+        let closed = Polygon::from_closed_path(points(&[(0, 0), (0, 3), (2, 3), (2, 0), (0, 0)]));
+        assert_eq!(closed.unwrap().vertices().len(), 4);
+    }
+
+    #[test]
+    fn test_self_intersection() {
+        /* a figure of eight crossing over at (2, 2) */
+        let eight = points(&[(0, 0), (0, 2), (4, 2), (4, 4), (2, 4), (2, 0)]);
+        assert_eq!(
+            Polygon::new(eight),
+            Err(PolygonError::SelfIntersecting(Point::new(2, 2)))
+        );
+        /* two squares touching at a corner */
+        let touching = points(&[
+            (0, 0),
+            (0, 2),
+            (2, 2),
+            (2, 4),
+            (4, 4),
+            (4, 2),
+            (2, 2),
+            (2, 0),
+        ]);
+        assert!(Polygon::new(touching).is_err());
+        /* doubling straight back */
+        let spike = points(&[(0, 0), (0, 2), (2, 2), (2, 5), (2, 1), (2, 0)]);
+        assert!(Polygon::new(spike).is_err());
+        /* collinear points along an edge are fine */
+        let cells = points(&[
+            (0, 0),
+            (0, 1),
+            (0, 2),
+            (1, 2),
+            (2, 2),
+            (2, 1),
+            (2, 0),
+            (1, 0),
+        ]);
+        assert!(Polygon::new(cells).is_ok());
+    }
+
+    #[test]
+    fn test_orientation() {
+        let polygon = l_shape();
+        assert_eq!(polygon.orientation(), Orientation::Clockwise);
+        let mut reversed = polygon.vertices().to_vec();
+        reversed.reverse();
+        let reversed = Polygon::new(reversed).unwrap();
+        assert_eq!(reversed.orientation(), Orientation::CounterClockwise);
+        assert_eq!(twice_area(&reversed), twice_area(&polygon));
+        let line = Polygon::new(points(&[(0, 0), (1, 1), (2, 2)])).unwrap();
+        assert_eq!(line.orientation(), Orientation::Degenerate);
+    }
+
+    #[test]
+    fn test_degenerate_interior() {
+        let line = Polygon::new(points(&[(0, 0), (1, 1), (2, 2)])).unwrap();
+        assert_eq!(interior_lattice_points(&line), 0);
+        let point = Polygon::new(points(&[(3, 3)])).unwrap();
+        assert_eq!(interior_lattice_points(&point), 0);
+        assert_eq!(interior_lattice_points(&l_shape()), 5);
+    }
+
+    #[test]
+    fn test_contains() {
+        let polygon = l_shape();
+        assert_eq!(polygon.contains(Point::new(1, 1)), Location::Inside);
+        assert_eq!(polygon.contains(Point::new(3, 3)), Location::Inside);
+        assert_eq!(polygon.contains(Point::new(1, 3)), Location::Outside);
+        assert_eq!(polygon.contains(Point::new(5, 1)), Location::Outside);
+        assert_eq!(polygon.contains(Point::new(0, 1)), Location::Boundary);
+        assert_eq!(polygon.contains(Point::new(2, 3)), Location::Boundary);
+        assert_eq!(polygon.contains(Point::new(4, 0)), Location::Boundary);
+
+        let triangle = Polygon::new(points(&[(0, 0), (0, 6), (4, 0)])).unwrap();
+        assert_eq!(triangle.contains(Point::new(2, 3)), Location::Boundary);
+        assert_eq!(triangle.contains(Point::new(1, 1)), Location::Inside);
+        assert_eq!(triangle.contains(Point::new(3, 3)), Location::Outside);
+    }
+
+    #[test]
+    fn test_bounding_box_and_rectilinear() {
+        let polygon = l_shape();
+        assert!(polygon.is_rectilinear());
+        assert_eq!(
+            polygon.bounding_box(),
+            BoundingBox {
+                min: Point::new(0, 0),
+                max: Point::new(4, 4)
+            }
+        );
+        let triangle = Polygon::new(points(&[(1, 0), (0, 6), (4, -2)])).unwrap();
+        assert!(!triangle.is_rectilinear());
+        assert_eq!(
+            triangle.bounding_box(),
+            BoundingBox {
+                min: Point::new(0, -2),
+                max: Point::new(4, 6)
+            }
+        );
+    }
+
+    #[test]
+    fn test_builder_collapses_collinear_moves() {
+        use Direction::*;
+        let mut builder = PolygonBuilder::new(Point::new(0, 0));
+        /* starting halfway along the top edge, so the start isn't a corner */
+        builder.extend([
+            (East, 1),
+            (East, 1),
+            (South, 2),
+            (West, 3),
+            (North, 2),
+            (East, 1),
+        ]);
+        assert_eq!(builder.position(), Point::new(0, 0));
+        assert_eq!(builder.perimeter(), 10);
+        let polygon = builder.build().unwrap();
+        assert_eq!(
+            polygon.vertices(),
+            points(&[(0, 2), (2, 2), (2, -1), (0, -1)])
+        );
+        assert_eq!(boundary_lattice_points(&polygon), builder.perimeter());
+        assert_eq!(interior_lattice_points(&polygon), 2);
+    }
+
+    #[test]
+    fn test_builder_trench() {
+        use Direction::*;
+        let mut builder = PolygonBuilder::new(Point::new(5, 5));
+        builder.step(South, 1).step(East, -2).step(North, 1);
+        assert_eq!(
+            builder.trench(),
+            points(&[(5, 5), (6, 5), (6, 4), (6, 3), (5, 3)])
+        );
+        assert_eq!(
+            builder.build(),
+            Err(PolygonError::NotClosed {
+                start: Point::new(5, 5),
+                end: Point::new(5, 3)
+            })
+        );
+        builder.step(East, 2);
+        assert_eq!(builder.trench().len(), 6);
+        assert_eq!(builder.build().unwrap().vertices().len(), 4);
+
+        let mut there_and_back = PolygonBuilder::new(Point::new(0, 0));
+        there_and_back.step(East, 3).step(West, 3);
+        assert!(there_and_back.build().is_err());
+    }
+}
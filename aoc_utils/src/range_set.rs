@@ -0,0 +1,257 @@
+use std::ops::{Add, Range};
+
+/// A set of values stored as half-open ranges. The ranges are always kept sorted, non-empty and
+/// apart from each other: overlapping or touching ranges are merged as soon as they meet.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: Copy + Ord> RangeSet<T> {
+    pub fn new() -> Self {
+        Self { ranges: vec![] }
+    }
+
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let after = self.ranges.partition_point(|range| range.start <= value);
+        after > 0 && value < self.ranges[after - 1].end
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let mut ranges = std::mem::take(&mut self.ranges);
+        ranges.push(range);
+        *self = ranges.into_iter().collect();
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.ranges
+            .iter()
+            .chain(other.ranges.iter())
+            .cloned()
+            .collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (&self.ranges[i], &other.ranges[j]);
+            let overlap = a.start.max(b.start)..a.end.min(b.end);
+            if !overlap.is_empty() {
+                ranges.push(overlap);
+            }
+            /* whichever range finishes first can't overlap anything further along */
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let mut j = 0;
+        for range in &self.ranges {
+            let mut start = range.start;
+            while j < other.ranges.len() && other.ranges[j].end <= start {
+                j += 1;
+            }
+            /* don't advance j past a cut which might reach into the next range too */
+            let mut k = j;
+            while k < other.ranges.len() && other.ranges[k].start < range.end {
+                let cut = &other.ranges[k];
+                if cut.start > start {
+                    ranges.push(start..cut.start);
+                }
+                start = start.max(cut.end);
+                k += 1;
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+        Self { ranges }
+    }
+
+    /// Moves every value in the set by `offset`.
+    pub fn shift<D: Copy>(&self, offset: D) -> Self
+    where
+        T: Add<D, Output = T>,
+    {
+        Self {
+            ranges: self
+                .ranges
+                .iter()
+                .map(|range| range.start + offset..range.end + offset)
+                .collect(),
+        }
+    }
+
+    /// Splits the set into the values below `at` and the values from `at` onwards.
+    pub fn split_at(&self, at: T) -> (Self, Self) {
+        let mut below = vec![];
+        let mut above = vec![];
+        for range in &self.ranges {
+            if range.end <= at {
+                below.push(range.clone());
+            } else if range.start >= at {
+                above.push(range.clone());
+            } else {
+                below.push(range.start..at);
+                above.push(at..range.end);
+            }
+        }
+        (Self { ranges: below }, Self { ranges: above })
+    }
+}
+
+impl<T: Copy + Ord> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy + Ord> From<Range<T>> for RangeSet<T> {
+    fn from(range: Range<T>) -> Self {
+        std::iter::once(range).collect()
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Range<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut sorted: Vec<Range<T>> = iter.into_iter().filter(|r| !r.is_empty()).collect();
+        sorted.sort_by_key(|range| range.start);
+        let mut ranges: Vec<Range<T>> = Vec::with_capacity(sorted.len());
+        for range in sorted {
+            match ranges.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => ranges.push(range),
+            }
+        }
+        Self { ranges }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::BTreeSet;
+
+    /* Small bounds keep the naive model cheap while still producing plenty of overlaps */
+    fn ranges() -> impl Strategy<Value = Vec<Range<i64>>> {
+        prop::collection::vec((-20i64..60, 0i64..15).prop_map(|(s, l)| s..s + l), 0..8)
+    }
+
+    fn model(ranges: &[Range<i64>]) -> BTreeSet<i64> {
+        ranges.iter().cloned().flatten().collect()
+    }
+
+    fn values(set: &RangeSet<i64>) -> BTreeSet<i64> {
+        model(set.ranges())
+    }
+
+    fn is_normalized(set: &RangeSet<i64>) -> bool {
+        set.ranges().iter().all(|range| !range.is_empty())
+            && set
+                .ranges()
+                .windows(2)
+                .all(|pair| pair[0].end < pair[1].start)
+    }
+
+    #[test]
+    fn test_merges_touching_ranges() {
+        let set: RangeSet<i64> = [5..8, 0..2, 2..4, 7..10, 12..12].into_iter().collect();
+        assert_eq!(set.ranges(), &[0..4, 5..10]);
+        assert!(set.contains(9));
+        assert!(!set.contains(4));
+        assert_eq!(set.min(), Some(0));
+    }
+
+    #[test]
+    fn test_difference_across_ranges() {
+        let set: RangeSet<i64> = [0..10, 20..30].into_iter().collect();
+        let cut = RangeSet::from(5..25);
+        assert_eq!(set.difference(&cut).ranges(), &[0..5, 25..30]);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_normalized(a in ranges()) {
+            let set: RangeSet<i64> = a.iter().cloned().collect();
+            prop_assert!(is_normalized(&set));
+            prop_assert_eq!(values(&set), model(&a));
+        }
+
+        #[test]
+        fn prop_insert(a in ranges(), r in (-20i64..60, 0i64..15)) {
+            let mut set: RangeSet<i64> = a.iter().cloned().collect();
+            set.insert(r.0..r.0 + r.1);
+            let mut expected = model(&a);
+            expected.extend(r.0..r.0 + r.1);
+            prop_assert!(is_normalized(&set));
+            prop_assert_eq!(values(&set), expected);
+        }
+
+        #[test]
+        fn prop_set_operations(a in ranges(), b in ranges()) {
+            let (set_a, set_b): (RangeSet<i64>, RangeSet<i64>) =
+                (a.iter().cloned().collect(), b.iter().cloned().collect());
+            let (model_a, model_b) = (model(&a), model(&b));
+
+            let union = set_a.union(&set_b);
+            prop_assert!(is_normalized(&union));
+            prop_assert_eq!(values(&union), &model_a | &model_b);
+
+            let intersection = set_a.intersection(&set_b);
+            prop_assert!(is_normalized(&intersection));
+            prop_assert_eq!(values(&intersection), &model_a & &model_b);
+
+            let difference = set_a.difference(&set_b);
+            prop_assert!(is_normalized(&difference));
+            prop_assert_eq!(values(&difference), &model_a - &model_b);
+        }
+
+        #[test]
+        fn prop_contains(a in ranges(), value in -25i64..80) {
+            let set: RangeSet<i64> = a.iter().cloned().collect();
+            prop_assert_eq!(set.contains(value), model(&a).contains(&value));
+        }
+
+        #[test]
+        fn prop_shift(a in ranges(), offset in -50i64..50) {
+            let set: RangeSet<i64> = a.iter().cloned().collect();
+            let shifted = set.shift(offset);
+            prop_assert!(is_normalized(&shifted));
+            prop_assert_eq!(values(&shifted), model(&a).iter().map(|v| v + offset).collect());
+        }
+
+        #[test]
+        fn prop_split_at(a in ranges(), at in -25i64..80) {
+            let set: RangeSet<i64> = a.iter().cloned().collect();
+            let (below, above) = set.split_at(at);
+            prop_assert!(is_normalized(&below) && is_normalized(&above));
+            let (expected_below, expected_above): (BTreeSet<i64>, BTreeSet<i64>) =
+                model(&a).into_iter().partition(|v| *v < at);
+            prop_assert_eq!(values(&below), expected_below);
+            prop_assert_eq!(values(&above), expected_above);
+        }
+    }
+}
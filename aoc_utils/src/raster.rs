@@ -0,0 +1,252 @@
+use crate::grid::Grid;
+use crate::polygon::{Location, Polygon};
+use crate::Point;
+use std::fmt;
+
+/* Every cell is classified on its own, so this is only meant for shapes small enough to look at */
+pub const MAX_RASTER_CELLS: usize = 1 << 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Cell {
+    Exterior,
+    Boundary,
+    Interior,
+}
+
+impl Cell {
+    pub fn symbol(self) -> char {
+        match self {
+            Self::Exterior => '.',
+            Self::Boundary => '#',
+            Self::Interior => 'I',
+        }
+    }
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+impl From<Location> for Cell {
+    fn from(location: Location) -> Self {
+        match location {
+            Location::Inside => Self::Interior,
+            Location::Boundary => Self::Boundary,
+            Location::Outside => Self::Exterior,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RasterTooLarge {
+    pub width: usize,
+    pub height: usize,
+}
+
+impl fmt::Display for RasterTooLarge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "a {}x{} raster is over the limit of {MAX_RASTER_CELLS} cells",
+            self.width, self.height
+        )
+    }
+}
+
+impl std::error::Error for RasterTooLarge {}
+
+/// A polygon drawn cell by cell. The grid covers the polygon's bounding box plus a border of one
+/// exterior cell all round, and its top left cell sits at `origin` in the polygon's coordinates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Raster {
+    pub origin: Point,
+    pub cells: Grid<Cell>,
+}
+
+impl Raster {
+    pub fn new(polygon: &Polygon) -> Result<Self, RasterTooLarge> {
+        let bounds = polygon.bounding_box();
+        let origin = bounds.min - Point::new(1, 1);
+        let size = bounds.max - bounds.min + Point::new(3, 3);
+        let (height, width) = <(usize, usize)>::try_from(size).unwrap();
+        if width
+            .checked_mul(height)
+            .is_none_or(|n| n > MAX_RASTER_CELLS)
+        {
+            return Err(RasterTooLarge { width, height });
+        }
+        let mut cells = Grid::new(width, height, Cell::Exterior);
+        for row in 0..height {
+            for col in 0..width {
+                let at = Point::try_from((row, col)).unwrap();
+                cells[at] = polygon.contains(origin + at).into();
+            }
+        }
+        Ok(Self { origin, cells })
+    }
+
+    /// The cell at `point` in the polygon's coordinates, anything off the raster being exterior.
+    pub fn get(&self, point: Point) -> Cell {
+        self.cells
+            .get(point - self.origin)
+            .copied()
+            .unwrap_or(Cell::Exterior)
+    }
+
+    pub fn count(&self, cell: Cell) -> usize {
+        self.cells.iter().filter(|&&c| c == cell).count()
+    }
+}
+
+impl fmt::Display for Raster {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.cells)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polygon::*;
+    use crate::Direction::{self, *};
+
+    /* the lagoon from day 18's example */
+    const LAGOON: [(Direction, i64); 14] = [
+        (East, 6),
+        (South, 5),
+        (West, 2),
+        (South, 2),
+        (East, 2),
+        (South, 2),
+        (West, 5),
+        (North, 2),
+        (West, 1),
+        (North, 2),
+        (East, 2),
+        (North, 3),
+        (West, 2),
+        (North, 2),
+    ];
+
+    const STAIRS: [(Direction, i64); 8] = [
+        (East, 2),
+        (South, 2),
+        (East, 2),
+        (South, 2),
+        (East, 2),
+        (South, 2),
+        (West, 6),
+        (North, 6),
+    ];
+
+    const COMB: [(Direction, i64); 12] = [
+        (East, 9),
+        (South, 6),
+        (West, 2),
+        (North, 4),
+        (West, 2),
+        (South, 4),
+        (West, 1),
+        (North, 4),
+        (West, 1),
+        (South, 4),
+        (West, 3),
+        (North, 6),
+    ];
+
+    /* Brute force: wall off the trench, then flood the outside in from the raster's corner */
+    fn flood_fill(trench: &[Point], origin: Point, width: usize, height: usize) -> Grid<Cell> {
+        let mut cells = Grid::new(width, height, Cell::Interior);
+        for &cell in trench {
+            cells[cell - origin] = Cell::Boundary;
+        }
+        let mut stack = vec![Point::new(0, 0)];
+        while let Some(at) = stack.pop() {
+            if cells[at] != Cell::Interior {
+                continue;
+            }
+            cells[at] = Cell::Exterior;
+            stack.extend(
+                cells
+                    .neighbors4(at)
+                    .filter(|(_, &cell)| cell == Cell::Interior)
+                    .map(|(next, _)| next),
+            );
+        }
+        cells
+    }
+
+    #[test]
+    fn test_matches_flood_fill() {
+        for moves in [&LAGOON[..], &STAIRS, &COMB] {
+            let mut builder = PolygonBuilder::new(Point::new(0, 0));
+            builder.extend(moves.iter().copied());
+            let polygon = builder.build().unwrap();
+            let raster = Raster::new(&polygon).unwrap();
+            let flooded = flood_fill(
+                &builder.trench(),
+                raster.origin,
+                raster.cells.width(),
+                raster.cells.height(),
+            );
+            assert_eq!(raster.cells, flooded, "\n{raster}");
+
+            let boundary = raster.count(Cell::Boundary) as i128;
+            let interior = raster.count(Cell::Interior) as i128;
+            assert_eq!(boundary, boundary_lattice_points(&polygon));
+            assert_eq!(interior, interior_lattice_points(&polygon));
+            let area = shoelace(&polygon);
+            assert_eq!(
+                picks_theorem_num_internal_points(area, boundary as i64) as i128,
+                interior
+            );
+        }
+    }
+
+    #[test]
+    fn test_drawing() {
+        let mut builder = PolygonBuilder::new(Point::new(0, 0));
+        builder.extend(LAGOON);
+        let raster = Raster::new(&builder.build().unwrap()).unwrap();
+        let expected = "\
+.........
+.#######.
+.#IIIII#.
+.###III#.
+...#III#.
+...#III#.
+.###I###.
+.#III#...
+.##II###.
+..#IIII#.
+..######.
+.........
+";
+        assert_eq!(raster.to_string(), expected);
+        assert_eq!(
+            raster.count(Cell::Boundary) + raster.count(Cell::Interior),
+            62
+        );
+        assert_eq!(raster.get(Point::new(-5, -5)), Cell::Exterior);
+        assert_eq!(raster.get(Point::new(0, 0)), Cell::Boundary);
+    }
+
+    #[test]
+    fn test_too_large() {
+        let polygon = Polygon::new(vec![
+            Point::new(0, 0),
+            Point::new(0, 5000),
+            Point::new(5000, 0),
+        ])
+        .unwrap();
+        assert_eq!(
+            Raster::new(&polygon),
+            Err(RasterTooLarge {
+                width: 5003,
+                height: 5003
+            })
+        );
+    }
+}
@@ -0,0 +1,4 @@
+//! Ways of looking at puzzle state other than printing the answer.
+
+pub mod svg;
+pub mod terminal;
@@ -0,0 +1,250 @@
+use crate::grid::Grid;
+use crate::polygon::Polygon;
+use crate::Point;
+use std::fmt;
+use std::io;
+use std::path::Path;
+
+/// The most user units a drawing spans in either direction. Anything bigger, like day 18's
+/// second lagoon, is scaled down to fit.
+pub const MAX_EXTENT: f64 = 1000.;
+/// The size of a grid cell, or of a unit step around a polygon, when there is room for it.
+pub const CELL_SIZE: f64 = 10.;
+const PADDING: f64 = 2.;
+
+/// An SVG drawing in puzzle coordinates: `row` runs down the page and `col` across it.
+#[derive(Debug, Clone)]
+pub struct Svg {
+    origin: Point,
+    scale: f64,
+    width: f64,
+    height: f64,
+    elements: Vec<String>,
+}
+
+impl Svg {
+    /// An empty drawing covering `rows` by `cols` units from `origin`.
+    fn blank(origin: Point, rows: i64, cols: i64) -> Self {
+        let extent = rows.max(cols).max(1) as f64;
+        let scale = CELL_SIZE.min(MAX_EXTENT / extent);
+        Self {
+            origin,
+            scale,
+            width: cols as f64 * scale + 2. * PADDING,
+            height: rows as f64 * scale + 2. * PADDING,
+            elements: vec![],
+        }
+    }
+
+    /// The outline of `polygon`, filled in.
+    pub fn polygon(polygon: &Polygon, fill: &str) -> Self {
+        let bounds = polygon.bounding_box();
+        let size = bounds.max - bounds.min;
+        let mut svg = Self::blank(bounds.min, size.row, size.col);
+        let points: Vec<String> = polygon
+            .vertices()
+            .iter()
+            .map(|&vertex| svg.position(vertex, 0.))
+            .collect();
+        svg.elements.push(format!(
+            r#"<polygon points="{}" fill="{fill}" stroke="black" stroke-width="1"/>"#,
+            points.join(" ")
+        ));
+        svg
+    }
+
+    /// Every cell of `grid` as a square, coloured by `colour` or left blank when it gives `None`.
+    pub fn grid<T>(grid: &Grid<T>, colour: impl Fn(&T) -> Option<&str>) -> Self {
+        let mut svg = Self::blank(Point::new(0, 0), grid.height() as i64, grid.width() as i64);
+        let (width, height) = (svg.width, svg.height);
+        svg.elements.push(format!(
+            r#"<rect x="0" y="0" width="{}" height="{}" fill="white"/>"#,
+            number(width),
+            number(height)
+        ));
+        for (point, cell) in grid.positions() {
+            if let Some(colour) = colour(cell) {
+                svg.push_cell(point, colour);
+            }
+        }
+        svg
+    }
+
+    /// Fills in the given cells on top of what is already drawn.
+    pub fn highlight(mut self, cells: impl IntoIterator<Item = Point>, colour: &str) -> Self {
+        for cell in cells {
+            self.push_cell(cell, colour);
+        }
+        self
+    }
+
+    /// Draws a line through the centres of the given cells, in order.
+    pub fn path(mut self, cells: impl IntoIterator<Item = Point>, colour: &str) -> Self {
+        let points: Vec<String> = cells
+            .into_iter()
+            .map(|cell| self.position(cell, 0.5))
+            .collect();
+        self.elements.push(format!(
+            r#"<polyline points="{}" fill="none" stroke="{colour}" stroke-width="{}"/>"#,
+            points.join(" "),
+            number((self.scale / 3.).max(0.5))
+        ));
+        self
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        std::fs::write(path, self.to_string())
+    }
+
+    fn push_cell(&mut self, cell: Point, colour: &str) {
+        let offset = cell - self.origin;
+        self.elements.push(format!(
+            r#"<rect x="{}" y="{}" width="{size}" height="{size}" fill="{colour}"/>"#,
+            number(PADDING + offset.col as f64 * self.scale),
+            number(PADDING + offset.row as f64 * self.scale),
+            size = number(self.scale)
+        ));
+    }
+
+    /* `shift` moves the point from a cell's top left corner, so 0.5 lands in its centre */
+    fn position(&self, point: Point, shift: f64) -> String {
+        let offset = point - self.origin;
+        format!(
+            "{},{}",
+            number(PADDING + (offset.col as f64 + shift) * self.scale),
+            number(PADDING + (offset.row as f64 + shift) * self.scale)
+        )
+    }
+}
+
+impl fmt::Display for Svg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (width, height) = (number(self.width), number(self.height));
+        writeln!(
+            f,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#
+        )?;
+        for element in &self.elements {
+            writeln!(f, "  {element}")?;
+        }
+        writeln!(f, "</svg>")
+    }
+}
+
+/* Two decimal places are plenty at this size and keep the files readable */
+fn number(value: f64) -> String {
+    let text = format!("{value:.2}");
+    text.trim_end_matches('0').trim_end_matches('.').to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coordinates(points: &str) -> Vec<(f64, f64)> {
+        points
+            .split_whitespace()
+            .map(|pair| {
+                let (x, y) = pair.split_once(',').unwrap();
+                (x.parse().unwrap(), y.parse().unwrap())
+            })
+            .collect()
+    }
+
+    fn attribute(node: roxmltree::Node, name: &str) -> f64 {
+        node.attribute(name).unwrap().parse().unwrap()
+    }
+
+    #[test]
+    fn test_small_polygon() {
+        let polygon = Polygon::new(vec![
+            Point::new(0, 0),
+            Point::new(0, 6),
+            Point::new(4, 6),
+            Point::new(4, 0),
+        ])
+        .unwrap();
+        let text = Svg::polygon(&polygon, "teal").to_string();
+        let document = roxmltree::Document::parse(&text).unwrap();
+        let root = document.root_element();
+        assert_eq!(root.tag_name().name(), "svg");
+        assert_eq!(attribute(root, "width"), 64.);
+        assert_eq!(attribute(root, "height"), 44.);
+
+        let shape = root.children().find(|n| n.has_tag_name("polygon")).unwrap();
+        assert_eq!(shape.attribute("fill"), Some("teal"));
+        assert_eq!(
+            coordinates(shape.attribute("points").unwrap()),
+            vec![(2., 2.), (62., 2.), (62., 42.), (2., 42.)]
+        );
+    }
+
+    #[test]
+    fn test_large_polygon_is_scaled() {
+        let big = 20_000_000;
+        let polygon = Polygon::new(vec![
+            Point::new(-big, -big),
+            Point::new(-big, big),
+            Point::new(big / 2, big),
+            Point::new(big / 2, -big),
+        ])
+        .unwrap();
+        let text = Svg::polygon(&polygon, "teal").to_string();
+        let document = roxmltree::Document::parse(&text).unwrap();
+        let root = document.root_element();
+        let (width, height) = (attribute(root, "width"), attribute(root, "height"));
+        assert_eq!(width, MAX_EXTENT + 2. * PADDING);
+        assert_eq!(height, 750. + 2. * PADDING);
+
+        let shape = root.children().find(|n| n.has_tag_name("polygon")).unwrap();
+        for (x, y) in coordinates(shape.attribute("points").unwrap()) {
+            assert!((0. ..=width).contains(&x) && (0. ..=height).contains(&y));
+        }
+    }
+
+    #[test]
+    fn test_grid_overlay() {
+        let grid = Grid::parse("#..\n.#.\n..#", |c| c == '#');
+        let svg = Svg::grid(&grid, |&wall| wall.then_some("black"))
+            .highlight([Point::new(0, 2)], "gold")
+            .path(
+                [Point::new(0, 0), Point::new(0, 1), Point::new(1, 1)],
+                "red",
+            );
+        let text = svg.to_string();
+        let document = roxmltree::Document::parse(&text).unwrap();
+        let root = document.root_element();
+
+        let cells: Vec<(f64, f64, &str)> = root
+            .children()
+            .filter(|n| n.has_tag_name("rect"))
+            .skip(1)
+            .map(|n| {
+                (
+                    attribute(n, "x"),
+                    attribute(n, "y"),
+                    n.attribute("fill").unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            cells,
+            vec![
+                (2., 2., "black"),
+                (12., 12., "black"),
+                (22., 22., "black"),
+                (22., 2., "gold")
+            ]
+        );
+
+        let line = root
+            .children()
+            .find(|n| n.has_tag_name("polyline"))
+            .unwrap();
+        assert_eq!(line.attribute("stroke"), Some("red"));
+        assert_eq!(
+            coordinates(line.attribute("points").unwrap()),
+            vec![(7., 7.), (17., 7.), (17., 17.)]
+        );
+    }
+}
@@ -0,0 +1,219 @@
+use crate::grid::Grid;
+use std::fmt::Write as _;
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+/// The eight standard ANSI colours, plus grey for things in the background.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Colour {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Grey,
+}
+
+impl Colour {
+    fn code(self) -> u8 {
+        match self {
+            Self::Black => 30,
+            Self::Red => 31,
+            Self::Green => 32,
+            Self::Yellow => 33,
+            Self::Blue => 34,
+            Self::Magenta => 35,
+            Self::Cyan => 36,
+            Self::White => 37,
+            Self::Grey => 90,
+        }
+    }
+}
+
+/// A single character of a frame, in the terminal's own colour when `colour` is `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Styled {
+    pub symbol: char,
+    pub colour: Option<Colour>,
+}
+
+impl Styled {
+    pub fn new(symbol: char, colour: Colour) -> Self {
+        Self {
+            symbol,
+            colour: Some(colour),
+        }
+    }
+
+    pub fn plain(symbol: char) -> Self {
+        Self {
+            symbol,
+            colour: None,
+        }
+    }
+}
+
+/// One still of an animation, as rows of styled characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    rows: Vec<Vec<Styled>>,
+}
+
+impl Frame {
+    pub fn new<R: IntoIterator<Item = Styled>>(rows: impl IntoIterator<Item = R>) -> Self {
+        Self {
+            rows: rows
+                .into_iter()
+                .map(|row| row.into_iter().collect())
+                .collect(),
+        }
+    }
+
+    pub fn from_grid<T>(grid: &Grid<T>, style: impl Fn(&T) -> Styled) -> Self {
+        Self::new(grid.rows().map(|row| row.iter().map(&style)))
+    }
+
+    /// The frame's characters without any colour, one line per row.
+    pub fn plain(&self) -> String {
+        let mut text = String::new();
+        for row in &self.rows {
+            text.extend(row.iter().map(|cell| cell.symbol));
+            text.push('\n');
+        }
+        text
+    }
+
+    /// The frame with ANSI colour codes, only switching colour where it changes along a row.
+    pub fn coloured(&self) -> String {
+        let mut text = String::new();
+        for row in &self.rows {
+            let mut current = None;
+            for cell in row {
+                if cell.colour != current {
+                    match cell.colour {
+                        Some(colour) => write!(text, "\x1b[{}m", colour.code()).unwrap(),
+                        None => text.push_str("\x1b[0m"),
+                    }
+                    current = cell.colour;
+                }
+                text.push(cell.symbol);
+            }
+            if current.is_some() {
+                text.push_str("\x1b[0m");
+            }
+            text.push('\n');
+        }
+        text
+    }
+}
+
+/// A sequence of frames to be replayed at `frame_rate` frames per second.
+#[derive(Debug, Clone)]
+pub struct Animation {
+    frames: Vec<Frame>,
+    frame_rate: f64,
+}
+
+impl Animation {
+    pub fn new(frame_rate: f64) -> Self {
+        assert!(frame_rate > 0., "the frame rate has to be positive");
+        Self {
+            frames: vec![],
+            frame_rate,
+        }
+    }
+
+    pub fn push(&mut self, frame: Frame) {
+        self.frames.push(frame);
+    }
+
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    pub fn frame_rate(&self) -> f64 {
+        self.frame_rate
+    }
+
+    pub fn set_frame_rate(&mut self, frame_rate: f64) {
+        assert!(frame_rate > 0., "the frame rate has to be positive");
+        self.frame_rate = frame_rate;
+    }
+
+    /// Plays the animation in the terminal, redrawing every frame in place.
+    pub fn play(&self, out: &mut impl Write) -> io::Result<()> {
+        let delay = Duration::from_secs_f64(1. / self.frame_rate);
+        for (i, frame) in self.frames.iter().enumerate() {
+            /* clear the screen and move the cursor back to the top left */
+            write!(out, "\x1b[2J\x1b[H")?;
+            write!(out, "{}", frame.coloured())?;
+            writeln!(out, "frame {}/{}", i + 1, self.frames.len())?;
+            out.flush()?;
+            thread::sleep(delay);
+        }
+        Ok(())
+    }
+
+    /// The first `n` frames as plain text, each under a numbered heading, for tests and logs.
+    pub fn dump(&self, n: usize) -> String {
+        let mut text = String::new();
+        for (i, frame) in self.frames.iter().take(n).enumerate() {
+            writeln!(text, "frame {}", i + 1).unwrap();
+            text.push_str(&frame.plain());
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn walls(c: &bool) -> Styled {
+        if *c {
+            Styled::new('#', Colour::Red)
+        } else {
+            Styled::plain('.')
+        }
+    }
+
+    #[test]
+    fn test_frame_text() {
+        let grid = Grid::parse("#..\n##.", |c| c == '#');
+        let frame = Frame::from_grid(&grid, walls);
+        assert_eq!(frame.plain(), "#..\n##.\n");
+        assert_eq!(frame.coloured(), "\x1b[31m#\x1b[0m..\n\x1b[31m##\x1b[0m.\n");
+        let all_walls = Frame::new([[Styled::new('#', Colour::Grey)]]);
+        assert_eq!(all_walls.coloured(), "\x1b[90m#\x1b[0m\n");
+    }
+
+    #[test]
+    fn test_dump() {
+        let mut animation = Animation::new(30.);
+        for i in 0..3 {
+            let grid = Grid::parse("...", |_| false);
+            let mut frame = Frame::from_grid(&grid, walls);
+            frame.rows[0][i] = Styled::new('#', Colour::Red);
+            animation.push(frame);
+        }
+        assert_eq!(animation.frames().len(), 3);
+        assert_eq!(animation.dump(2), "frame 1\n#..\nframe 2\n.#.\n");
+        assert_eq!(animation.dump(10).lines().count(), 6);
+    }
+
+    #[test]
+    fn test_play() {
+        let mut animation = Animation::new(1000.);
+        animation.push(Frame::new([[Styled::plain('x')]]));
+        animation.push(Frame::new([[Styled::plain('y')]]));
+        let mut out = vec![];
+        animation.play(&mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(out.matches("\x1b[2J").count(), 2);
+        assert!(out.ends_with("y\nframe 2/2\n"));
+    }
+}
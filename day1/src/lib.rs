@@ -0,0 +1,516 @@
+#[cfg(test)]
+use aoc_utils::{answers::assert_answers, input::read_file};
+use aoc_utils::{csv, Solution};
+use std::collections::BTreeMap;
+use std::fmt::{self, Write as _};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<String>;
+    type Answer = usize;
+    type Error = CalibrationError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(input.lines().map(|line| line.to_owned()).collect())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        calibration_sum(input, &Vocabulary::digits())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        calibration_sum(input, &Vocabulary::english())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CalibrationError {
+    /// A line with no number on it, `line` counting from 1.
+    NoDigit { line: usize, text: String },
+    /// A line whose numbers joined together don't fit in a `usize`.
+    TooLarge { line: usize, text: String },
+    /// The calibration values add up to more than a `usize` holds, from `line` on.
+    TotalTooLarge { line: usize },
+}
+
+impl fmt::Display for CalibrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoDigit { line, text } => write!(f, "line {line} has no digit: {text:?}"),
+            Self::TooLarge { line, text } => {
+                write!(
+                    f,
+                    "line {line} makes a calibration value too large: {text:?}"
+                )
+            }
+            Self::TotalTooLarge { line } => {
+                write!(f, "the calibration values overflow when adding line {line}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CalibrationError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VocabularyError {
+    EmptyWord,
+}
+
+impl fmt::Display for VocabularyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyWord => write!(f, "a vocabulary can't have an empty word"),
+        }
+    }
+}
+
+impl std::error::Error for VocabularyError {}
+
+const ENGLISH: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+const GERMAN: [&str; 10] = [
+    "null", "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+];
+
+/// The words that stand for numbers, each with its value. The digits 0-9 are always included.
+/// Words can't be empty, as an empty word would be found everywhere.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vocabulary {
+    words: Vec<(String, usize)>,
+}
+
+impl Vocabulary {
+    pub fn new<S: Into<String>>(
+        words: impl IntoIterator<Item = (S, usize)>,
+    ) -> Result<Self, VocabularyError> {
+        let digits = (0..10).map(|value| (value.to_string(), value));
+        /* a word given twice keeps its last value */
+        let words: BTreeMap<String, usize> = digits
+            .chain(words.into_iter().map(|(word, value)| (word.into(), value)))
+            .collect();
+        if words.contains_key("") {
+            return Err(VocabularyError::EmptyWord);
+        }
+        let mut words: Vec<(String, usize)> = words.into_iter().collect();
+        /* longest first, so a word like "eighteen" wins over the "eight" it starts with */
+        words.sort_by_key(|(word, _)| std::cmp::Reverse(word.len()));
+        Ok(Self { words })
+    }
+
+    /* for the built in vocabularies, none of which has an empty word */
+    fn known(words: impl IntoIterator<Item = (&'static str, usize)>) -> Self {
+        Self::new(words).expect("the built in vocabularies have no empty words")
+    }
+
+    /// Just the digits, as in part 1.
+    pub fn digits() -> Self {
+        Self::known([])
+    }
+
+    /// The digits and their English names, as in part 2.
+    pub fn english() -> Self {
+        Self::known(ENGLISH.into_iter().zip(0..))
+    }
+
+    pub fn german() -> Self {
+        Self::known(GERMAN.into_iter().zip(0..))
+    }
+
+    /// The same vocabulary, also knowing the given words. Words it already knows take the new
+    /// values.
+    pub fn with<S: Into<String>>(
+        self,
+        words: impl IntoIterator<Item = (S, usize)>,
+    ) -> Result<Self, VocabularyError> {
+        Self::new(
+            self.words
+                .into_iter()
+                .chain(words.into_iter().map(|(word, value)| (word.into(), value))),
+        )
+    }
+
+    fn token_starting_at<'a>(&self, line: &'a str, offset: usize) -> Option<Token<'a>> {
+        let rest = &line[offset..];
+        self.words
+            .iter()
+            .find(|(word, _)| rest.starts_with(word.as_str()))
+            .map(|(word, value)| Token {
+                offset,
+                text: &rest[..word.len()],
+                value: *value,
+            })
+    }
+
+    fn token_ending_at<'a>(&self, line: &'a str, end: usize) -> Option<Token<'a>> {
+        let before = &line[..end];
+        self.words
+            .iter()
+            .find(|(word, _)| before.ends_with(word.as_str()))
+            .map(|(word, value)| Token {
+                offset: end - word.len(),
+                text: &before[end - word.len()..],
+                value: *value,
+            })
+    }
+}
+
+/// A number found in a line, `offset` being the byte it starts at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token<'a> {
+    pub offset: usize,
+    pub text: &'a str,
+    pub value: usize,
+}
+
+/* Words can share letters, as in "eightwo", so rather than consuming matches the scanners try
+every position in turn and ask what token starts (or, going backwards, ends) there. Going
+backwards by ends keeps the two directions mirror images: "took 10" ends in "10", not "0". */
+
+/// The token starting furthest to the left, the longest one if several start there.
+pub fn first_token<'a>(line: &'a str, vocabulary: &Vocabulary) -> Option<Token<'a>> {
+    line.char_indices()
+        .find_map(|(offset, _)| vocabulary.token_starting_at(line, offset))
+}
+
+/// The token ending furthest to the right, the longest one if several end there.
+pub fn last_token<'a>(line: &'a str, vocabulary: &Vocabulary) -> Option<Token<'a>> {
+    line.char_indices()
+        .rev()
+        .find_map(|(offset, c)| vocabulary.token_ending_at(line, offset + c.len_utf8()))
+}
+
+/// The first and last numbers of the line written one after the other, so a line reading
+/// "twelve ... 3" gives 123. `None` when the line has no numbers at all, or when the joined
+/// number is too large for a `usize`.
+pub fn calibration_value(line: &str, vocabulary: &Vocabulary) -> Option<usize> {
+    let first = first_token(line, vocabulary)?;
+    let last = last_token(line, vocabulary)?;
+    let last_digits = last.value.checked_ilog10().unwrap_or(0) + 1;
+    first
+        .value
+        .checked_mul(10usize.checked_pow(last_digits)?)?
+        .checked_add(last.value)
+}
+
+fn calibration_sum(lines: &[String], vocabulary: &Vocabulary) -> Result<usize, CalibrationError> {
+    lines
+        .iter()
+        .zip(1..)
+        .try_fold(0usize, |total, (text, line)| {
+            let value = calibration_value(text, vocabulary).ok_or_else(|| {
+                let text = text.clone();
+                match first_token(&text, vocabulary) {
+                    None => CalibrationError::NoDigit { line, text },
+                    Some(_) => CalibrationError::TooLarge { line, text },
+                }
+            })?;
+            total
+                .checked_add(value)
+                .ok_or(CalibrationError::TotalTooLarge { line })
+        })
+}
+
+/// What was read from one line of the calibration document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineReport<'a> {
+    pub text: &'a str,
+    pub first: Option<Token<'a>>,
+    pub last: Option<Token<'a>>,
+    pub value: Option<usize>,
+}
+
+pub fn report<'a>(lines: &'a [String], vocabulary: &Vocabulary) -> Vec<LineReport<'a>> {
+    lines
+        .iter()
+        .map(|line| LineReport {
+            text: line,
+            first: first_token(line, vocabulary),
+            last: last_token(line, vocabulary),
+            value: calibration_value(line, vocabulary),
+        })
+        .collect()
+}
+
+/// The report as a table, lines without any digits flagged rather than given a value.
+pub fn report_text(report: &[LineReport]) -> String {
+    let width = report
+        .iter()
+        .map(|line| line.text.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(4);
+    let token = |token: Option<Token>| {
+        token.map_or(String::new(), |token| {
+            format!("{}@{}", token.text, token.offset)
+        })
+    };
+    let mut text = format!(
+        "line  {:<width$}  {:>12}  {:>12}  value\n",
+        "text", "first", "last"
+    );
+    for (i, line) in report.iter().enumerate() {
+        let value = match (line.value, line.first) {
+            (Some(value), _) => value.to_string(),
+            (None, None) => "NO DIGIT".to_owned(),
+            (None, Some(_)) => "TOO LARGE".to_owned(),
+        };
+        writeln!(
+            text,
+            "{:>4}  {:<width$}  {:>12}  {:>12}  {value}",
+            i + 1,
+            line.text,
+            token(line.first),
+            token(line.last)
+        )
+        .unwrap();
+    }
+    let total = report
+        .iter()
+        .filter_map(|line| line.value)
+        .try_fold(0usize, usize::checked_add)
+        .map_or("too large".to_owned(), |total| total.to_string());
+    let missing = report.iter().filter(|line| line.first.is_none()).count();
+    writeln!(text, "total {total}, {missing} lines without a digit").unwrap();
+    text
+}
+
+pub const REPORT_CSV_HEADER: &str = "line,text,first,first_offset,last,last_offset,value";
+
+/// The report as CSV, lines without any digits having empty token and value fields.
+pub fn report_csv(report: &[LineReport]) -> String {
+    let mut rows = format!("{REPORT_CSV_HEADER}\n");
+    for (i, line) in report.iter().enumerate() {
+        let fields = |token: Option<Token>| {
+            token.map_or(",".to_owned(), |token| {
+                format!("{},{}", csv::field(token.text), token.offset)
+            })
+        };
+        writeln!(
+            rows,
+            "{},{},{},{},{}",
+            i + 1,
+            csv::field(line.text),
+            fields(line.first),
+            fields(line.last),
+            line.value.map_or(String::new(), |value| value.to_string())
+        )
+        .unwrap();
+    }
+    rows
+}
+
+#[test]
+fn test_answers() {
+    assert_answers::<Day1>("answers.txt");
+}
+
+#[test]
+fn test_vocabularies() {
+    let (digits, english) = (Vocabulary::digits(), Vocabulary::english());
+    assert_eq!(calibration_value("a1b2c3d4e5f", &digits), Some(15));
+    assert_eq!(calibration_value("treb7uchet", &digits), Some(77));
+    assert_eq!(calibration_value("two1nine", &digits), Some(11));
+    assert_eq!(calibration_value("two1nine", &english), Some(29));
+    assert_eq!(calibration_value("eightwothree", &digits), None);
+    assert_eq!(calibration_value("", &english), None);
+
+    let german = Vocabulary::german();
+    assert_eq!(calibration_value("zweiundfünfzig", &german), Some(25));
+    assert_eq!(calibration_value("achtzehn", &german), Some(88));
+    assert_eq!(calibration_value("two1nine", &german), Some(11));
+}
+
+#[test]
+fn test_multi_digit_tokens() {
+    let vocabulary = Vocabulary::english()
+        .with([("eighteen", 18), ("twelve", 12), ("dozen", 12)])
+        .unwrap();
+    assert_eq!(calibration_value("eighteen", &vocabulary), Some(1818));
+    assert_eq!(
+        calibration_value("twelve apples and 3 pears", &vocabulary),
+        Some(123)
+    );
+    assert_eq!(calibration_value("a dozen or so", &vocabulary), Some(1212));
+    assert_eq!(calibration_value("eight", &vocabulary), Some(88));
+    assert_eq!(
+        calibration_value("eighteen", &Vocabulary::english()),
+        Some(88)
+    );
+
+    /* numbers can be written out as digits too */
+    let logs = Vocabulary::digits()
+        .with([("10", 10), ("100", 100)])
+        .unwrap();
+    assert_eq!(
+        calibration_value("took 100ms, retried 10 times", &logs),
+        Some(10010)
+    );
+    assert_eq!(last_token("x101", &logs).unwrap().text, "1");
+    let renumbered = logs.with([("1", 7)]).unwrap();
+    assert_eq!(calibration_value("x1y", &renumbered), Some(77));
+}
+
+#[test]
+fn test_no_digit() {
+    /* the part 2 sample spells out some lines' numbers, which part 1 can't read */
+    let lines = Day1::parse(&read_file("small_input.txt")).unwrap();
+    assert_eq!(
+        Day1::part1(&lines),
+        Err(CalibrationError::NoDigit {
+            line: 2,
+            text: "eightwothree".to_owned()
+        })
+    );
+    assert_eq!(Day1::part2(&lines), Ok(281));
+}
+
+#[test]
+fn test_empty_word() {
+    assert_eq!(
+        Vocabulary::english().with([("", 7)]),
+        Err(VocabularyError::EmptyWord)
+    );
+    assert_eq!(Vocabulary::new([("", 7)]), Err(VocabularyError::EmptyWord));
+}
+
+#[test]
+fn test_overflow() {
+    let huge = Vocabulary::digits()
+        .with([("huge", usize::MAX), ("half", usize::MAX / 2)])
+        .unwrap();
+    assert_eq!(calibration_value("huge", &huge), None);
+    assert_eq!(calibration_value("1 huge", &huge), None);
+    assert_eq!(calibration_value("huge 1", &huge), None);
+    let lines = owned_lines(&["1", "huge 1"]);
+    assert_eq!(
+        calibration_sum(&lines, &huge),
+        Err(CalibrationError::TooLarge {
+            line: 2,
+            text: "huge 1".to_owned()
+        })
+    );
+    /* "0half" is just the half, so it takes three of them to overflow the total */
+    let lines = owned_lines(&["0half", "0half", "0half"]);
+    assert_eq!(
+        calibration_sum(&lines, &huge),
+        Err(CalibrationError::TotalTooLarge { line: 3 })
+    );
+    let text = report_text(&report(&owned_lines(&["1 huge"]), &huge));
+    assert!(
+        text.contains("TOO LARGE\ntotal 0, 0 lines without a digit"),
+        "{text}"
+    );
+}
+
+#[test]
+fn test_token_offsets() {
+    let english = Vocabulary::english();
+    let line = "xtwone3four";
+    assert_eq!(
+        first_token(line, &english),
+        Some(Token {
+            offset: 1,
+            text: "two",
+            value: 2
+        })
+    );
+    assert_eq!(
+        last_token(line, &english),
+        Some(Token {
+            offset: 7,
+            text: "four",
+            value: 4
+        })
+    );
+    assert_eq!(first_token(line, &Vocabulary::digits()).unwrap().offset, 6);
+    assert_eq!(first_token("é7", &Vocabulary::digits()).unwrap().offset, 2);
+    assert_eq!(
+        last_token("fünfsechs", &Vocabulary::german()),
+        Some(Token {
+            offset: 5,
+            text: "sechs",
+            value: 6
+        })
+    );
+}
+
+#[test]
+fn test_overlapping_pairs() {
+    let english = Vocabulary::english();
+    let mut pairs = 0;
+    for (a, first) in ENGLISH.iter().enumerate() {
+        for (b, second) in ENGLISH.iter().enumerate() {
+            /* every way the end of `first` can double as the start of `second` */
+            for shared in 1..first.len().min(second.len()) {
+                if !first.ends_with(&second[..shared]) {
+                    continue;
+                }
+                let line = format!("{first}{}", &second[shared..]);
+                let found = (
+                    first_token(&line, &english).unwrap(),
+                    last_token(&line, &english).unwrap(),
+                );
+                assert_eq!((found.0.text, found.1.text), (*first, *second), "{line}");
+                assert_eq!(found.1.offset, first.len() - shared, "{line}");
+                assert_eq!(calibration_value(&line, &english), Some(a * 10 + b));
+                pairs += 1;
+            }
+        }
+    }
+    assert_eq!(pairs, 9);
+}
+
+#[test]
+fn test_known_overlaps() {
+    let english = Vocabulary::english();
+    for (line, value) in [
+        ("oneight", 18),
+        ("twone", 21),
+        ("threeight", 38),
+        ("fiveight", 58),
+        ("sevenine", 79),
+        ("eightwo", 82),
+        ("eighthree", 83),
+        ("nineight", 98),
+        ("zerone", 1),
+        ("xeightwothreex", 83),
+    ] {
+        assert_eq!(calibration_value(line, &english), Some(value), "{line}");
+    }
+}
+
+#[cfg(test)]
+fn owned_lines(lines: &[&str]) -> Vec<String> {
+    lines.iter().map(|line| line.to_string()).collect()
+}
+
+#[test]
+fn test_report_text() {
+    let lines = owned_lines(&["two1nine", "abc", "eightwo"]);
+    let text = report_text(&report(&lines, &Vocabulary::english()));
+    let expected = "\
+line  text             first          last  value
+   1  two1nine         two@0        nine@4  29
+   2  abc                                   NO DIGIT
+   3  eightwo        eight@0         two@4  82
+total 111, 1 lines without a digit
+";
+    assert_eq!(text, expected);
+}
+
+#[test]
+fn test_report_csv() {
+    let lines = owned_lines(&["1abc2", "no, \"digits\"", "fünf4"]);
+    let csv = report_csv(&report(&lines, &Vocabulary::german()));
+    let expected = "\
+line,text,first,first_offset,last,last_offset,value
+1,1abc2,1,0,2,4,12
+2,\"no, \"\"digits\"\"\",,,,,
+3,fünf4,fünf,0,4,5,54
+";
+    assert_eq!(csv, expected);
+}
@@ -0,0 +1,217 @@
+#[cfg(test)]
+use aoc_utils::{answers::assert_answers, input::read_file};
+use aoc_utils::{
+    grid::Grid,
+    polygon::{interior_lattice_points, Point, PolygonBuilder, PolygonError},
+    render::svg::Svg,
+    Direction, Solution,
+};
+use core::fmt;
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+
+lazy_static! {
+    static ref MOVEMENT_CHOICES: HashMap<PipeShape, (Direction, Direction)> = {
+        let mut map = HashMap::new();
+        map.insert(PipeShape::NS, (Direction::North, Direction::South));
+        map.insert(PipeShape::NE, (Direction::North, Direction::East));
+        map.insert(PipeShape::NW, (Direction::North, Direction::West));
+        map.insert(PipeShape::EW, (Direction::East, Direction::West));
+        map.insert(PipeShape::SE, (Direction::South, Direction::East));
+        map.insert(PipeShape::SW, (Direction::South, Direction::West));
+        map
+    };
+}
+
+#[derive(Debug, Default)]
+struct Cursor {
+    came_from: Option<Direction>,
+    coords: Point,
+}
+
+#[derive(Debug, Clone, PartialEq, Hash, Eq)]
+pub enum PipeShape {
+    NS,
+    EW,
+    NE,
+    NW,
+    SW,
+    SE,
+    Start,
+    G, //Maybe just None?.. see later
+}
+
+impl PipeShape {
+    fn from(s: char) -> Self {
+        match s {
+            '|' => Self::NS,
+            '-' => Self::EW,
+            'L' => Self::NE,
+            'J' => Self::NW,
+            '7' => Self::SW,
+            'F' => Self::SE,
+            '.' => Self::G,
+            'S' => Self::Start, //Figure out later!
+            _ => panic!("Invalid pipe shape!"),
+        }
+    }
+}
+
+impl fmt::Display for PipeShape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let repr = match self {
+            Self::NS => "↕️",
+            Self::EW => "↔️",
+            Self::NE => "↪️",
+            Self::NW => "↩️",
+            Self::SW => "↖️",
+            Self::SE => "↗️",
+            Self::G => "⏹️",
+            Self::Start => "▶️",
+        };
+        write!(f, "{}", repr)
+    }
+}
+
+pub struct PipeMap {
+    pipes: Grid<PipeShape>,
+    start: Option<Point>,
+}
+
+impl fmt::Display for PipeMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.pipes.rows() {
+            for beep in row {
+                write!(f, "{beep}   ")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl PipeMap {
+    fn trace_loop(&self) -> Option<PolygonBuilder> {
+        let start = self.start?;
+        let mut cursor = Cursor {
+            coords: start,
+            came_from: None,
+        };
+
+        let mut pipe_loop = PolygonBuilder::new(start);
+        loop {
+            cursor = take_one_loop_step(cursor, &self.pipes);
+            let heading = cursor.came_from.unwrap().opposite();
+            pipe_loop.step(heading, 1);
+            if cursor.coords == start {
+                return Some(pipe_loop);
+            }
+        }
+    }
+}
+
+fn parse_input(input: &str) -> (Grid<PipeShape>, Option<Point>) {
+    let pipes = Grid::parse(input, PipeShape::from);
+    let start = pipes.find(|pipe| *pipe == PipeShape::Start);
+    (pipes, start)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = PipeMap;
+    type Answer = i64;
+    type Error = PolygonError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let (mut pipes, start) = parse_input(input);
+        if let Some(start) = start {
+            replace_s(start, &mut pipes);
+        }
+        Ok(PipeMap { pipes, start })
+    }
+
+    fn part1(pipe_map: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(pipe_map
+            .trace_loop()
+            .map_or(0, |pipe_loop| pipe_loop.perimeter() as i64 / 2))
+    }
+
+    fn part2(pipe_map: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        match pipe_map.trace_loop() {
+            Some(pipe_loop) => Ok(interior_lattice_points(&pipe_loop.build()?) as i64),
+            None => Ok(0),
+        }
+    }
+}
+
+/// The main loop as a filled in polygon, or `None` if the map has no start to trace it from.
+pub fn render_loop(pipe_map: &PipeMap) -> Result<Option<Svg>, PolygonError> {
+    let Some(pipe_loop) = pipe_map.trace_loop() else {
+        return Ok(None);
+    };
+    Ok(Some(Svg::polygon(&pipe_loop.build()?, "lightgreen")))
+}
+
+fn replace_s(start: Point, pipes: &mut Grid<PipeShape>) {
+    let mut connection_directions: Vec<Direction> = vec![];
+    if let Some(east) = pipes.get(start + Direction::East.offset()) {
+        if matches!(east, PipeShape::EW | PipeShape::NW | PipeShape::SW) {
+            connection_directions.push(Direction::East);
+        }
+    }
+    if let Some(west) = pipes.get(start + Direction::West.offset()) {
+        if matches!(west, PipeShape::EW | PipeShape::SE | PipeShape::NE) {
+            connection_directions.push(Direction::West);
+        }
+    }
+    if let Some(north) = pipes.get(start + Direction::North.offset()) {
+        if matches!(north, PipeShape::SW | PipeShape::SE | PipeShape::NS) {
+            connection_directions.push(Direction::North);
+        }
+    }
+    if let Some(south) = pipes.get(start + Direction::South.offset()) {
+        if matches!(south, PipeShape::NW | PipeShape::NE | PipeShape::NS) {
+            connection_directions.push(Direction::South);
+        }
+    }
+    connection_directions.sort();
+    pipes[start] = match (&connection_directions[0], &connection_directions[1]) {
+        (Direction::North, Direction::South) => PipeShape::NS,
+        (Direction::North, Direction::East) => PipeShape::NE,
+        (Direction::North, Direction::West) => PipeShape::NW,
+        (Direction::East, Direction::South) => PipeShape::SE,
+        (Direction::South, Direction::West) => PipeShape::SW,
+        (Direction::East, Direction::West) => PipeShape::EW,
+        _ => panic!("Impossible setup!"),
+    };
+}
+
+fn take_one_loop_step(cursor: Cursor, pipes: &Grid<PipeShape>) -> Cursor {
+    let Cursor { came_from, coords } = cursor;
+
+    let here = &pipes[coords];
+    let directions_to_check = MOVEMENT_CHOICES.get(here).unwrap();
+    let checking = if Some(directions_to_check.0) != came_from {
+        directions_to_check.0
+    } else {
+        directions_to_check.1
+    };
+    Cursor {
+        coords: coords + checking.offset(),
+        came_from: Some(checking.opposite()),
+    }
+}
+
+#[test]
+fn replace_s_works() {
+    let (mut pipes, start) = parse_input(&read_file("sample_input1.txt"));
+    let start = start.unwrap();
+    replace_s(start, &mut pipes);
+    assert_eq!(pipes[start], PipeShape::SE);
+}
+
+#[test]
+fn test_answers() {
+    assert_answers::<Day10>("answers.txt");
+}
@@ -0,0 +1,196 @@
+#[cfg(test)]
+use aoc_utils::{answers::assert_answers, input::read_file};
+use aoc_utils::{grid::Grid, polygon::Point, Solution};
+use itertools::Itertools;
+use std::convert::Infallible;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq, Copy)]
+pub enum Tile {
+    Space,
+    Galaxy,
+}
+
+impl Tile {
+    fn from_char(c: char) -> Self {
+        match c {
+            '.' => Self::Space,
+            '#' => Self::Galaxy,
+            _ => panic!("invalid tile type!"),
+        }
+    }
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let repr = match self {
+            Self::Space => ".",
+            Self::Galaxy => "#",
+        };
+        write!(f, "{repr}")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ExpandedTile {
+    actual_width: usize,
+    actual_height: usize,
+    tile: Tile,
+}
+
+impl fmt::Display for ExpandedTile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.actual_width, self.actual_height)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpaceMap(Grid<Tile>);
+
+impl SpaceMap {
+    fn expand_space(&self, expansion_factor: usize) -> ExpandedMap {
+        let heights: Vec<usize> = self
+            .0
+            .rows()
+            .map(|row| expanded_size(row.iter(), expansion_factor))
+            .collect();
+        let widths: Vec<usize> = self
+            .0
+            .cols()
+            .map(|col| expanded_size(col, expansion_factor))
+            .collect();
+        let mut expanded_map = ExpandedMap(self.0.map(|t| ExpandedTile {
+            actual_height: 1,
+            actual_width: 1,
+            tile: *t,
+        }));
+        for (row, height) in heights.iter().enumerate() {
+            for (col, width) in widths.iter().enumerate() {
+                let tile = &mut expanded_map.0[Point::new(row as i64, col as i64)];
+                tile.actual_height = *height;
+                tile.actual_width = *width;
+            }
+        }
+        expanded_map
+    }
+}
+
+fn expanded_size<'a>(mut line: impl Iterator<Item = &'a Tile>, expansion_factor: usize) -> usize {
+    if line.all(|t| *t == Tile::Space) {
+        expansion_factor
+    } else {
+        1
+    }
+}
+
+impl fmt::Display for SpaceMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.0.rows() {
+            for tile in row {
+                write!(f, "{tile} ")?;
+            }
+            writeln!(f)?;
+        }
+        fmt::Result::Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct ExpandedMap(Grid<ExpandedTile>);
+impl fmt::Display for ExpandedMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.0.rows() {
+            for tile in row {
+                write!(f, "{tile} ")?;
+            }
+            writeln!(f)?;
+        }
+        fmt::Result::Ok(())
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = SpaceMap;
+    type Answer = usize;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(SpaceMap(Grid::parse(input, Tile::from_char)))
+    }
+
+    fn part1(space_map: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(total_distance(space_map, 2))
+    }
+
+    fn part2(space_map: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(total_distance(space_map, 1000000))
+    }
+}
+
+fn total_distance(space_map: &SpaceMap, expansion_factor: usize) -> usize {
+    let space_map = space_map.expand_space(expansion_factor);
+
+    collect_galaxies(space_map)
+        .iter()
+        .combinations(2)
+        .map(|galaxy_pair| galaxy_pair[0].manhattan_distance(*galaxy_pair[1]) as usize)
+        .sum()
+}
+
+fn collect_galaxies(space_map: ExpandedMap) -> Vec<Point> {
+    let mut cursor_row = 0;
+    let mut cursor_col;
+    let mut galaxies: Vec<Point> = vec![];
+    for row in space_map.0.rows() {
+        cursor_col = 0;
+        for tile in row.iter() {
+            if tile.tile == Tile::Galaxy {
+                galaxies.push(Point {
+                    row: cursor_row,
+                    col: cursor_col,
+                });
+            }
+            cursor_col += tile.actual_width as i64;
+        }
+        cursor_row += row[0].actual_height as i64;
+    }
+    galaxies
+}
+
+#[cfg(test)]
+fn create_space_map(filename: &str) -> SpaceMap {
+    Day11::parse(&read_file(filename)).unwrap()
+}
+
+#[test]
+fn sample_test_factor10() {
+    assert_eq!(
+        total_distance(&create_space_map("sample_input.txt"), 10),
+        1030
+    );
+}
+
+#[test]
+fn sample_test_factor100() {
+    assert_eq!(
+        total_distance(&create_space_map("sample_input.txt"), 100),
+        8410
+    );
+}
+#[test]
+fn test_create_space_map() {
+    let space_map = create_space_map("sample_input.txt").expand_space(10);
+    println!("{space_map}");
+    assert_eq!(space_map.0[Point::new(0, 2)].actual_width, 10);
+    assert_eq!(space_map.0[Point::new(0, 5)].actual_width, 10);
+    assert_eq!(space_map.0[Point::new(0, 8)].actual_width, 10);
+    assert_eq!(space_map.0[Point::new(3, 0)].actual_height, 10);
+    assert_eq!(space_map.0[Point::new(3, 0)].actual_width, 1);
+}
+
+#[test]
+fn test_answers() {
+    assert_answers::<Day11>("answers.txt");
+}
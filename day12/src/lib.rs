@@ -0,0 +1,125 @@
+#[cfg(test)]
+use aoc_utils::answers::assert_answers;
+use aoc_utils::Solution;
+use std::collections::HashMap;
+use std::convert::Infallible;
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+struct State {
+    dot: Option<usize>,
+    hash: Option<usize>,
+}
+
+pub struct Record {
+    springs: String,
+    checks: Vec<usize>,
+}
+
+impl Record {
+    fn count_arrangements(&self, unfold: usize) -> usize {
+        let checks = self.checks.repeat(unfold);
+        let states = create_states(checks);
+        let mut input = self.springs.clone();
+        input.push('?');
+        input = input.repeat(unfold);
+        let len = input.len();
+        input.remove(len - 1);
+
+        count(input, &states)
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Record>;
+    type Answer = usize;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(input
+            .lines()
+            .map(|line| {
+                let split: Vec<&str> = line.split_whitespace().collect();
+                Record {
+                    springs: split[0].to_owned(),
+                    checks: split[1].split(',').map(|x| x.parse().unwrap()).collect(),
+                }
+            })
+            .collect())
+    }
+
+    fn part1(records: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(records.iter().map(|r| r.count_arrangements(1)).sum())
+    }
+
+    fn part2(records: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(records.iter().map(|r| r.count_arrangements(5)).sum())
+    }
+}
+
+/* DFA method obtained from this article:
+https://alexoxorn.github.io/posts/aoc-day12-regular_languages/
+What I learned using this method:
+    * All regex can be represented as a DFA
+    * It's easier and safer to work with Option'd indexes to a vector in Rust
+    * Sometimes you don't need to create 800 new types and enums to solve a simple problem
+*/
+fn create_states(checks: Vec<usize>) -> Vec<State> {
+    let mut states: Vec<State> = vec![State {
+        dot: Some(0),
+        hash: Some(1),
+    }];
+    let states_size = checks.iter().sum::<usize>() + checks.len() + 1;
+    states.reserve(states_size);
+    for check in &checks {
+        for _ in 1..*check {
+            states.push(State {
+                dot: None,
+                hash: Some(states.len() + 1),
+            });
+        }
+        if states.len() + 1 < states_size {
+            states.push(State {
+                dot: Some(states.len() + 1),
+                hash: None,
+            });
+            states.push(State {
+                dot: Some(states.len()),
+                hash: Some(states.len() + 1),
+            });
+        }
+    }
+    states.remove(states_size - 1);
+    let new_len = states.len();
+    states[new_len - 1].hash = None;
+    states[new_len - 1].dot = Some(new_len - 1);
+    states
+}
+
+fn count(input: String, states: &[State]) -> usize {
+    let mut curr_map: HashMap<State, usize> = HashMap::from([(states[0], 1)]);
+    for c in input.chars() {
+        let mut next_map: HashMap<State, usize> = HashMap::new();
+        curr_map.iter().for_each(|(key, value)| {
+            if let Some(dot) = key.dot {
+                if c == '.' || c == '?' {
+                    *next_map.entry(states[dot]).or_insert(0) += value;
+                }
+            }
+            if let Some(hash) = key.hash {
+                if c == '#' || c == '?' {
+                    *next_map.entry(states[hash]).or_insert(0) += value;
+                }
+            }
+        });
+        curr_map = next_map;
+    }
+    let last_state = states[states.len() - 1];
+    *curr_map.get(&last_state).unwrap_or(&0)
+}
+
+#[test]
+fn test_answers() {
+    assert_answers::<Day12>("answers.txt");
+}
@@ -0,0 +1,90 @@
+#[cfg(test)]
+use aoc_utils::answers::assert_answers;
+use aoc_utils::{
+    matrix::{Matrix, MatrixView},
+    Solution,
+};
+use std::convert::Infallible;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Matrix>;
+    type Answer = usize;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let mut matrices: Vec<Matrix> = vec![];
+        let mut matrix: Matrix = Vec::new();
+        for line in input.lines() {
+            if line.is_empty() {
+                matrices.push(matrix.clone());
+                matrix.clear();
+            } else {
+                matrix.push(line.chars().collect());
+            }
+        }
+        matrices.push(matrix);
+        Ok(matrices)
+    }
+
+    fn part1(matrices: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(matrices.iter().map(|m| calculate_matrix(m, 0)).sum())
+    }
+
+    fn part2(matrices: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(matrices.iter().map(|m| calculate_matrix(m, 1)).sum())
+    }
+}
+
+/* `smudges` is the exact number of mismatched cells the reflection has to contain */
+fn calculate_matrix(matrix: &Matrix, smudges: usize) -> usize {
+    let view = MatrixView::new(matrix);
+    if let Some(symmetry_line) = find_symmetry_line(view, smudges) {
+        100 * symmetry_line
+    } else {
+        find_symmetry_line(view.transpose(), smudges).expect("really expect symmetry at this point")
+    }
+}
+
+/* Looking for columns instead of rows is just the same search over a transposed view */
+fn find_symmetry_line(view: MatrixView<char>, smudges: usize) -> Option<usize> {
+    (1..view.num_rows()).find(|&line| {
+        smudged_equality(view, line - 1, line, smudges).is_some()
+            && check_symmetry(view, line, smudges).is_some()
+    })
+}
+
+fn smudged_equality(
+    view: MatrixView<char>,
+    row1: usize,
+    row2: usize,
+    smudges: usize,
+) -> Option<usize> {
+    let num_mismatched = view
+        .row(row1)
+        .zip(view.row(row2))
+        .filter(|(a, b)| a != b)
+        .count();
+    (num_mismatched <= smudges).then_some(num_mismatched)
+}
+
+/* Walks outwards from the line in mirrored pairs until one side runs out of rows */
+fn check_symmetry(view: MatrixView<char>, symmetry_line: usize, smudges: usize) -> Option<usize> {
+    let reach = symmetry_line.min(view.num_rows() - symmetry_line);
+    let mut smudge_sum = 0;
+    for offset in 0..reach {
+        smudge_sum += smudged_equality(
+            view,
+            symmetry_line - 1 - offset,
+            symmetry_line + offset,
+            smudges,
+        )?;
+    }
+    (smudge_sum == smudges).then_some(symmetry_line)
+}
+
+#[test]
+fn test_answers() {
+    assert_answers::<Day13>("answers.txt");
+}
@@ -0,0 +1,164 @@
+use aoc_utils::{
+    cycle::find_cycle_in_place,
+    matrix::{matrix_rotate_ccw, matrix_rotate_cw, matrix_rotate_cw_in_place, Matrix, MatrixView},
+    render::terminal::{Animation, Colour, Frame, Styled},
+    Solution,
+};
+use std::convert::Infallible;
+
+#[cfg(test)]
+use aoc_utils::{answers::assert_answers, input::read_file};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Matrix;
+    type Answer = usize;
+    type Error = Infallible;
+
+    /* The platform is stored rotated counter-clockwise, so that north is at the start of every row */
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let grid: Matrix = input.lines().map(|line| line.chars().collect()).collect();
+        Ok(matrix_rotate_ccw(grid))
+    }
+
+    fn part1(matrix: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        let mut matrix = matrix.clone();
+        tilt_matrix(&mut matrix);
+        Ok(north_load(&matrix))
+    }
+
+    fn part2(matrix: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(spin_load(matrix, 1000000000))
+    }
+}
+
+/* Spinning settles into a loop long before a billion cycles, so only the first pass around
+the loop is simulated */
+fn spin_load(input_matrix: &Matrix, spin_for: usize) -> usize {
+    let cycle = find_cycle_in_place(input_matrix.clone(), |matrix| spin_matrix(matrix, 1, None));
+    north_load(cycle.state_at(spin_for))
+}
+
+/* North is at the start of every row, so a rock's load is its distance from the end of the row */
+fn north_load(matrix: &Matrix) -> usize {
+    matrix
+        .iter()
+        .flat_map(|row| {
+            row.iter()
+                .enumerate()
+                .filter(|(_, c)| **c == 'O')
+                .map(|(i, _)| row.len() - i)
+        })
+        .sum()
+}
+
+fn tilt_matrix(matrix: &mut Matrix) {
+    for row in matrix.iter_mut() {
+        for segment in row.split_mut(|c| *c == '#') {
+            let num_rocks = segment.iter().filter(|c| **c == 'O').count();
+            let (rocks, space) = segment.split_at_mut(num_rocks);
+            rocks.fill('O');
+            space.fill('.');
+        }
+    }
+}
+
+#[cfg(test)]
+fn read_matrix(filename: &str) -> Matrix {
+    let input = read_file(filename);
+    Day14::parse(&input).unwrap()
+}
+
+#[test]
+fn test_tilt_cycles() {
+    let mut one_cycle = read_matrix("sample_input.txt");
+    spin_matrix(&mut one_cycle, 1, None);
+
+    let reference = read_matrix("sample_one_cycle.txt");
+    assert_eq!(one_cycle, reference);
+}
+
+#[test]
+fn test_rectangular_platform() {
+    let mut platform = Day14::parse(".O..\nO..#").unwrap();
+    spin_matrix(&mut platform, 1, None);
+    assert_eq!(platform, Day14::parse("....\n.OO#").unwrap());
+}
+
+/* Tilting always rolls rocks towards the start of the rows, rotating after each tilt
+brings the next direction of the cycle around. Both happen in place when the platform is square. */
+fn spin_matrix(matrix: &mut Matrix, num_spins: usize, mut animation: Option<&mut Animation>) {
+    for i in 0..(4 * num_spins) {
+        tilt_matrix(matrix);
+        if let Some(animation) = animation.as_deref_mut() {
+            animation.push(platform_frame(matrix, i));
+        }
+        rotate_cw(matrix);
+    }
+}
+
+fn rotate_cw(matrix: &mut Matrix) {
+    if matrix.len() == matrix.first().map_or(0, Vec::len) {
+        matrix_rotate_cw_in_place(matrix);
+    } else {
+        *matrix = matrix_rotate_cw(std::mem::take(matrix));
+    }
+}
+
+/* The matrix starts out rotated counter-clockwise and has turned clockwise once after every
+earlier tilt, so turning the view on to the next full turn shows the platform north side up */
+fn platform_frame(matrix: &Matrix, earlier_tilts: usize) -> Frame {
+    let mut view = MatrixView::new(matrix);
+    for _ in 0..(5 - earlier_tilts % 4) % 4 {
+        view = view.rotate_cw();
+    }
+    Frame::new(view.rows().map(|row| {
+        row.map(|&c| match c {
+            'O' => Styled::new(c, Colour::Yellow),
+            '#' => Styled::new(c, Colour::Grey),
+            _ => Styled::plain(c),
+        })
+    }))
+}
+
+/// Records every tilt of the first `num_spins` spin cycles, starting from the untouched platform.
+pub fn animate_spins(matrix: &Matrix, num_spins: usize, frame_rate: f64) -> Animation {
+    let mut animation = Animation::new(frame_rate);
+    animation.push(platform_frame(matrix, 0));
+    spin_matrix(&mut matrix.clone(), num_spins, Some(&mut animation));
+    animation
+}
+
+#[test]
+fn spin_cycle_test() {
+    let mut three_spins = read_matrix("sample_input.txt");
+    spin_matrix(&mut three_spins, 3, None);
+    let mut ten_spins = read_matrix("sample_input.txt");
+    spin_matrix(&mut ten_spins, 17, None);
+    assert_eq!(three_spins, ten_spins);
+}
+
+#[test]
+fn test_spin_animation() {
+    let matrix = read_matrix("sample_input.txt");
+    let animation = animate_spins(&matrix, 1, 10.);
+    assert_eq!(animation.frames().len(), 5);
+    let frames = animation.dump(5);
+    let frames: Vec<Vec<&str>> = frames
+        .split("frame ")
+        .skip(1)
+        .map(|frame| frame.lines().skip(1).collect())
+        .collect();
+    let start = read_file("sample_input.txt");
+    let one_cycle = read_file("sample_one_cycle.txt");
+    assert_eq!(frames[0], start.lines().collect::<Vec<_>>());
+    assert_eq!(frames[4], one_cycle.lines().collect::<Vec<_>>());
+    /* tilted north, so no rock has a free space above it */
+    assert_eq!(frames[1][..2], ["OOOO.#.O..", "OO..#....#"]);
+}
+
+#[test]
+fn test_answers() {
+    assert_answers::<Day14>("answers.txt");
+}
@@ -0,0 +1,274 @@
+#[cfg(test)]
+use aoc_utils::{answers::assert_answers, input::read_file};
+use aoc_utils::{
+    grid::Grid,
+    polygon::Point,
+    render::{
+        svg::Svg,
+        terminal::{Animation, Colour, Frame, Styled},
+    },
+    Direction, Solution,
+};
+use std::{
+    convert::{From, Infallible},
+    fmt::Display,
+};
+
+pub type MirrorMatrix = Grid<Tile>;
+
+trait Energize {
+    /* every beam segment adds a frame to the animation, when there is one */
+    fn energize(
+        &mut self,
+        heading: Direction,
+        start_point: Point,
+        animation: Option<&mut Animation>,
+    );
+    fn energize_from(
+        &mut self,
+        heading: Direction,
+        mirror_point: Point,
+        animation: Option<&mut Animation>,
+    );
+    fn clear_energy(&mut self);
+    fn count_energy(&self) -> usize;
+}
+
+impl Energize for MirrorMatrix {
+    fn count_energy(&self) -> usize {
+        self.iter().filter(|tile| tile.energised).count()
+    }
+
+    fn clear_energy(&mut self) {
+        self.iter_mut().for_each(|cell| cell.energised = false);
+    }
+
+    fn energize(
+        &mut self,
+        heading: Direction,
+        start_point: Point,
+        mut animation: Option<&mut Animation>,
+    ) {
+        let vertical = matches!(heading, Direction::North | Direction::South);
+        let mut point = start_point;
+        while let Some(tile) = self.get_mut(point) {
+            let was_energised = tile.energised;
+            tile.energised = true;
+            match (tile.mirror, vertical) {
+                (Some(Mirror::BackSlash), false) | (Some(Mirror::ForwardSlash), true) => {
+                    self.energize_from(heading.turn_right(), point, animation);
+                    return;
+                }
+                (Some(Mirror::BackSlash), true) | (Some(Mirror::ForwardSlash), false) => {
+                    self.energize_from(heading.turn_left(), point, animation);
+                    return;
+                }
+                /* a splitter hit side-on sends beams both ways, unless an earlier beam already did */
+                (Some(Mirror::SplitterH), true) | (Some(Mirror::SplitterV), false) => {
+                    if !was_energised {
+                        self.energize_from(heading.turn_left(), point, animation.as_deref_mut());
+                        self.energize_from(heading.turn_right(), point, animation);
+                    }
+                    return;
+                }
+                _ => point += heading.offset(),
+            }
+        }
+    }
+
+    /* starts a new segment on the tile after the mirror which turned the beam */
+    fn energize_from(
+        &mut self,
+        heading: Direction,
+        mirror_point: Point,
+        mut animation: Option<&mut Animation>,
+    ) {
+        if let Some(animation) = animation.as_deref_mut() {
+            animation.push(beam_frame(self));
+        }
+        self.energize(heading, mirror_point + heading.offset(), animation);
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Mirror {
+    BackSlash,
+    ForwardSlash,
+    SplitterH,
+    SplitterV,
+}
+
+impl Mirror {
+    fn symbol(self) -> char {
+        match self {
+            Self::BackSlash => '\\',
+            Self::ForwardSlash => '/',
+            Self::SplitterH => '-',
+            Self::SplitterV => '|',
+        }
+    }
+}
+
+impl From<char> for Mirror {
+    fn from(c: char) -> Self {
+        match c {
+            '\\' => Self::BackSlash,
+            '/' => Self::ForwardSlash,
+            '-' => Self::SplitterH,
+            '|' => Self::SplitterV,
+            _ => panic!("Unexpected mirror shape {c} encountered"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Tile {
+    mirror: Option<Mirror>,
+    energised: bool,
+}
+
+impl Display for Tile {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.energised {
+            write!(fmt, "#")
+        } else {
+            write!(fmt, ".")
+        }
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = MirrorMatrix;
+    type Answer = usize;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(create_mirror_matrix(input))
+    }
+
+    fn part1(mirrors: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        let mut mirrors = mirrors.clone();
+        mirrors.energize(Direction::East, Point { row: 0, col: 0 }, None);
+        Ok(mirrors.count_energy())
+    }
+
+    fn part2(mirrors: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        let mut mirrors = mirrors.clone();
+        let mut maximums: Vec<usize> = vec![];
+        for c in 0..mirrors.width() as i64 {
+            let a = {
+                mirrors.clear_energy();
+                mirrors.energize(Direction::South, Point { row: 0, col: c }, None);
+                mirrors.count_energy()
+            };
+            let b = {
+                mirrors.clear_energy();
+                mirrors.energize(
+                    Direction::North,
+                    Point {
+                        row: mirrors.height() as i64 - 1,
+                        col: c,
+                    },
+                    None,
+                );
+                mirrors.count_energy()
+            };
+            maximums.push(std::cmp::max(a, b));
+        }
+        for r in 0..mirrors.height() as i64 {
+            let a = {
+                mirrors.clear_energy();
+                mirrors.energize(Direction::East, Point { row: r, col: 0 }, None);
+                mirrors.count_energy()
+            };
+            let b = {
+                mirrors.clear_energy();
+                mirrors.energize(
+                    Direction::West,
+                    Point {
+                        row: r,
+                        col: mirrors.width() as i64 - 1,
+                    },
+                    None,
+                );
+                mirrors.count_energy()
+            };
+            maximums.push(std::cmp::max(a, b));
+        }
+        Ok(*maximums.iter().max().unwrap())
+    }
+}
+
+/// The contraption with the tiles energized by part 1's beam lit up and the mirrors in grey.
+pub fn render_energized(mirrors: &MirrorMatrix) -> Svg {
+    let mut mirrors = mirrors.clone();
+    mirrors.energize(Direction::East, Point { row: 0, col: 0 }, None);
+    Svg::grid(&mirrors, |tile| match (tile.energised, tile.mirror) {
+        (true, Some(_)) => Some("darkorange"),
+        (true, None) => Some("gold"),
+        (false, Some(_)) => Some("dimgray"),
+        (false, None) => None,
+    })
+}
+
+fn beam_frame(mirrors: &MirrorMatrix) -> Frame {
+    Frame::from_grid(mirrors, |tile| match (tile.energised, tile.mirror) {
+        (true, Some(mirror)) => Styled::new(mirror.symbol(), Colour::Yellow),
+        (true, None) => Styled::new('#', Colour::Yellow),
+        (false, Some(mirror)) => Styled::new(mirror.symbol(), Colour::Grey),
+        (false, None) => Styled::plain('.'),
+    })
+}
+
+/// Records part 1's beam spreading through the contraption, one frame per straight run.
+pub fn animate_beam(mirrors: &MirrorMatrix, frame_rate: f64) -> Animation {
+    let mut mirrors = mirrors.clone();
+    let mut animation = Animation::new(frame_rate);
+    animation.push(beam_frame(&mirrors));
+    mirrors.energize(
+        Direction::East,
+        Point { row: 0, col: 0 },
+        Some(&mut animation),
+    );
+    animation.push(beam_frame(&mirrors));
+    animation
+}
+
+fn create_mirror_matrix(input: &str) -> MirrorMatrix {
+    Grid::parse(input, |c| Tile {
+        mirror: if c == '.' {
+            None
+        } else {
+            Some(Mirror::from(c))
+        },
+        energised: false,
+    })
+}
+
+#[test]
+fn test_beam_animation() {
+    let mirrors = Day16::parse(&read_file("sample_input.txt")).unwrap();
+    let animation = animate_beam(&mirrors, 10.);
+    let frames = animation.frames();
+    assert!(frames[0]
+        .plain()
+        .lines()
+        .eq(read_file("sample_input.txt").lines()));
+    let mut energized = mirrors.clone();
+    energized.energize(Direction::East, Point { row: 0, col: 0 }, None);
+    assert_eq!(frames.last(), Some(&beam_frame(&energized)));
+    let lit = frames.last().unwrap().plain().matches('#').count();
+    let lit_mirrors = energized
+        .iter()
+        .filter(|tile| tile.energised && tile.mirror.is_some())
+        .count();
+    assert_eq!(lit + lit_mirrors, Day16::part1(&mirrors).unwrap());
+    assert!(animation.dump(2).starts_with("frame 1\n.|...\\....\n"));
+}
+
+#[test]
+fn test_answers() {
+    assert_answers::<Day16>("answers.txt");
+}
@@ -0,0 +1,228 @@
+#[cfg(test)]
+use aoc_utils::{answers::assert_answers, input::read_file};
+use aoc_utils::{grid::Grid, polygon::Point, render::svg::Svg, Direction, Solution};
+use std::convert::Infallible;
+use std::{
+    cmp::{Ord, Ordering, PartialOrd},
+    collections::{BinaryHeap, HashMap},
+};
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+struct State {
+    cost: usize,
+    position: Position,
+    num_steps: usize,
+    /* the state we stepped here from, so the route can be traced back from the goal */
+    previous: Option<(Position, usize)>,
+}
+
+impl Ord for State {
+    /* inverted logic here ensures we have a max heap and not a min heap */
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .cost
+            .cmp(&self.cost)
+            .then_with(|| self.position.cmp(&other.position))
+    }
+}
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Position {
+    heading: Direction,
+    location: Point,
+}
+
+impl Position {
+    fn turn_and_step(&self, heading: Direction) -> Self {
+        Self {
+            location: self.location + heading.offset(),
+            heading,
+        }
+    }
+    fn step(&self) -> Self {
+        self.turn_and_step(self.heading)
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Grid<usize>;
+    type Answer = usize;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(Grid::parse(input, |c| c.to_digit(10).unwrap() as usize))
+    }
+
+    fn part1(heat_loss: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(min_heat_loss(heat_loss, 1, 3))
+    }
+
+    fn part2(heat_loss: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(min_heat_loss(heat_loss, 4, 10))
+    }
+}
+
+fn min_heat_loss(heat_loss: &Grid<usize>, min_steps: usize, max_steps: usize) -> usize {
+    best_route(heat_loss, min_steps, max_steps).0
+}
+
+fn best_route(heat_loss: &Grid<usize>, min_steps: usize, max_steps: usize) -> (usize, Vec<Point>) {
+    let start = Point::new(0, 0);
+    let end = Point::new(heat_loss.height() as i64 - 1, heat_loss.width() as i64 - 1);
+    dijkstra_binary_heap(heat_loss, start, end, min_steps, max_steps).unwrap()
+}
+
+/* From cool to hot, indexed by a block's heat loss */
+const HEAT_COLOURS: [&str; 10] = [
+    "#ffffff", "#fff5eb", "#fee6ce", "#fdd0a2", "#fdae6b", "#fd8d3c", "#f16913", "#d94801",
+    "#a63603", "#7f2704",
+];
+
+/// The city blocks shaded by heat loss, with the crucible's cheapest route for `part` drawn on.
+pub fn render_route(heat_loss: &Grid<usize>, part: u8) -> Svg {
+    let (min_steps, max_steps) = if part == 1 { (1, 3) } else { (4, 10) };
+    let (_, route) = best_route(heat_loss, min_steps, max_steps);
+    Svg::grid(heat_loss, |&loss| HEAT_COLOURS.get(loss).copied()).path(route, "blue")
+}
+
+fn dijkstra_binary_heap(
+    heat_loss: &Grid<usize>,
+    start: Point,
+    goal: Point,
+    min_steps: usize,
+    max_steps: usize,
+) -> Option<(usize, Vec<Point>)> {
+    let mut distances = Grid::new(heat_loss.width(), heat_loss.height(), usize::MAX);
+    let mut heap = BinaryHeap::new();
+    let mut seen: HashMap<(Position, usize), Option<(Position, usize)>> = HashMap::new();
+
+    /* Modifications to a normal heap based dijkstra are as follows:
+      A node (i.e. state) is normally the cost we paid to enter it and any associated data.
+      Here, however, we can enter a node from various directions, and having had taken a various
+      number of steps. Each of these permutations is a actually a different state to be in, as our
+      choice are influenced by these parameters.
+
+      Therefore, we need to track both the cost of the node, it's location, where we came from, and how
+    many steps we took before we got to it.
+
+    This heap based dijkstra uses a "seen"/visited list as opposed to an explicit iteration over all nodes.
+    */
+    distances[start] = 0;
+    heap.push(State {
+        cost: 0,
+        position: Position {
+            heading: Direction::East,
+            location: start,
+        },
+        num_steps: 0,
+        previous: None,
+    });
+    heap.push(State {
+        cost: 0,
+        position: Position {
+            heading: Direction::South,
+            location: start,
+        },
+        num_steps: 0,
+        previous: None,
+    });
+
+    while let Some(State {
+        cost,
+        position,
+        num_steps,
+        previous,
+    }) = heap.pop()
+    {
+        /* Did we find the goal? Cool, let's return the cost and how we got there */
+        if position.location == goal {
+            let mut route = vec![position.location];
+            let mut state = previous;
+            while let Some(earlier) = state {
+                route.push(earlier.0.location);
+                state = seen[&earlier];
+            }
+            route.reverse();
+            return Some((cost, route));
+        }
+
+        /* if the cost is already worse than our minimum value found, why bother caclulating further? */
+        if cost > distances[position.location] {
+            continue;
+        }
+
+        /* Only the first, cheapest, visit to a state counts. Remember where it came from for the route */
+        if seen.contains_key(&(position, num_steps)) {
+            continue;
+        }
+        seen.insert((position, num_steps), previous);
+        let here = Some((position, num_steps));
+
+        /* The following situation calculates up to 3 possible new states that have to be added to the heap for processing, depending
+        on the num_steps constraints provided as input */
+        if num_steps >= min_steps {
+            let left = position.turn_and_step(position.heading.turn_left());
+            if heat_loss.contains(left.location) {
+                heap.push(State {
+                    cost: cost + heat_loss[left.location],
+                    position: left,
+                    num_steps: 1,
+                    previous: here,
+                });
+            }
+            let right = position.turn_and_step(position.heading.turn_right());
+            if heat_loss.contains(right.location) {
+                heap.push(State {
+                    cost: cost + heat_loss[right.location],
+                    position: right,
+                    num_steps: 1,
+                    previous: here,
+                });
+            }
+        }
+
+        let forward = position.step();
+        if num_steps < max_steps && heat_loss.contains(forward.location) {
+            heap.push(State {
+                cost: cost + heat_loss[forward.location],
+                position: forward,
+                num_steps: num_steps + 1,
+                previous: here,
+            });
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+fn read_heat_loss(filename: &str) -> Grid<usize> {
+    Day17::parse(&read_file(filename)).unwrap()
+}
+
+#[test]
+fn test_sample1() {
+    let heat_loss = read_heat_loss("sample_input.txt");
+    assert_eq!(min_heat_loss(&heat_loss, 0, 3), 102);
+}
+
+#[test]
+fn test_sample_route() {
+    let heat_loss = read_heat_loss("sample_input.txt");
+    let (cost, route) = best_route(&heat_loss, 1, 3);
+    assert_eq!(route.first(), Some(&Point::new(0, 0)));
+    assert_eq!(route.last(), Some(&Point::new(12, 12)));
+    let entered: usize = route[1..].iter().map(|&block| heat_loss[block]).sum();
+    assert_eq!(entered, cost);
+}
+
+#[test]
+fn test_answers() {
+    assert_answers::<Day17>("answers.txt");
+}
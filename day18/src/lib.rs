@@ -0,0 +1,120 @@
+#[cfg(test)]
+use aoc_utils::raster::{Cell, Raster};
+#[cfg(test)]
+use aoc_utils::{answers::assert_answers, input::read_file};
+use aoc_utils::{
+    polygon::{interior_lattice_points, Point, PolygonBuilder, PolygonError},
+    render::svg::Svg,
+    Direction, Solution,
+};
+
+/* The hex codes put the direction in their last digit */
+fn direction_from_code(code: i64) -> Direction {
+    match code {
+        0 => Direction::East,
+        1 => Direction::South,
+        2 => Direction::West,
+        3 => Direction::North,
+        _ => panic!("unexpected direction!"),
+    }
+}
+
+pub struct DigStep {
+    direction: Direction,
+    steps: i64,
+    color_code: i64,
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<DigStep>;
+    type Answer = i128;
+    type Error = PolygonError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(input
+            .lines()
+            .map(|line| {
+                let line: Vec<&str> = line.split_whitespace().collect();
+                let code: String = line[2].chars().filter(|c| c.is_alphanumeric()).collect();
+                DigStep {
+                    direction: line[0].parse().unwrap_or_else(|e| panic!("{e}")),
+                    steps: line[1].parse().unwrap(),
+                    color_code: i64::from_str_radix(&code, 16).unwrap(),
+                }
+            })
+            .collect())
+    }
+
+    fn part1(plan: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        lagoon_size(plan.iter().map(DigStep::as_written))
+    }
+
+    fn part2(plan: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        lagoon_size(plan.iter().map(DigStep::decoded))
+    }
+}
+
+impl DigStep {
+    fn as_written(&self) -> (Direction, i64) {
+        (self.direction, self.steps)
+    }
+
+    /* The real instructions were hidden in the colour codes all along */
+    fn decoded(&self) -> (Direction, i64) {
+        (
+            direction_from_code(self.color_code & 0xF),
+            self.color_code >> 4,
+        )
+    }
+}
+
+/// The outline of the lagoon dug by `part`'s reading of the plan.
+pub fn render_lagoon(plan: &[DigStep], part: u8) -> Result<Svg, PolygonError> {
+    let trench = if part == 1 {
+        dig_trench(plan.iter().map(DigStep::as_written))
+    } else {
+        dig_trench(plan.iter().map(DigStep::decoded))
+    };
+    Ok(Svg::polygon(&trench.build()?, "steelblue"))
+}
+
+fn dig_trench(moves: impl Iterator<Item = (Direction, i64)>) -> PolygonBuilder {
+    let mut trench = PolygonBuilder::new(Point::new(0, 0));
+    trench.extend(moves);
+    trench
+}
+
+fn lagoon_size(moves: impl Iterator<Item = (Direction, i64)>) -> Result<i128, PolygonError> {
+    let trench = dig_trench(moves);
+    let polygon = trench.build()?;
+    Ok(trench.perimeter() + interior_lattice_points(&polygon))
+}
+
+#[test]
+fn test_sample_raster() {
+    let plan = Day18::parse(&read_file("sample_input.txt")).unwrap();
+    let trench = dig_trench(plan.iter().map(DigStep::as_written));
+    let raster = Raster::new(&trench.build().unwrap()).unwrap();
+    assert_eq!(raster.count(Cell::Boundary), trench.trench().len());
+    assert_eq!(
+        raster.count(Cell::Boundary) + raster.count(Cell::Interior),
+        62
+    );
+}
+
+#[test]
+fn test_unclosed_lagoon() {
+    let plan = Day18::parse("R 6 (#70c710)\nD 5 (#0dc571)").unwrap();
+    assert!(matches!(
+        render_lagoon(&plan, 1),
+        Err(PolygonError::NotClosed { .. })
+    ));
+    assert!(render_lagoon(&Day18::parse(&read_file("sample_input.txt")).unwrap(), 2).is_ok());
+}
+
+#[test]
+fn test_answers() {
+    assert_answers::<Day18>("answers.txt");
+}
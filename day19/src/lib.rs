@@ -0,0 +1,350 @@
+#[cfg(test)]
+use aoc_utils::answers::assert_answers;
+use aoc_utils::Solution;
+use std::{
+    collections::HashMap,
+    convert::{From, Infallible},
+    ops::Range,
+};
+
+pub struct System {
+    workflows: HashMap<String, Workflow>,
+    parts: Vec<[i64; 4]>,
+}
+
+impl System {
+    fn accepted(&self, component: Component) -> Vec<Component> {
+        let urnode = Node {
+            component,
+            target: Target::Workflow("in".to_owned()),
+        };
+        let mut queue = vec![urnode];
+        let mut accepted: Vec<Component> = vec![];
+        while let Some(node) = queue.pop() {
+            match node.target {
+                Target::Rejected => (),
+                Target::Accepted => accepted.push(node.component),
+                Target::Workflow(ref key) => {
+                    let workflow = self.workflows.get(key).unwrap();
+                    workflow.evaluate(node, &mut queue);
+                }
+            }
+        }
+        accepted
+    }
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = System;
+    type Answer = i64;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let mut lines = input.lines();
+        let workflows = read_workflows(lines.by_ref());
+        let parts = lines.map(read_part).collect();
+        Ok(System { workflows, parts })
+    }
+
+    fn part1(system: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        /* A single part is just a component whose ranges are one rating wide */
+        Ok(system
+            .parts
+            .iter()
+            .filter(|[x, m, a, s]| {
+                let component = Component {
+                    x: *x..x + 1,
+                    m: *m..m + 1,
+                    a: *a..a + 1,
+                    s: *s..s + 1,
+                };
+                !system.accepted(component).is_empty()
+            })
+            .map(|part| part.iter().sum::<i64>())
+            .sum())
+    }
+
+    fn part2(system: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        let component = Component {
+            x: 1..4001,
+            m: 1..4001,
+            a: 1..4001,
+            s: 1..4001,
+        };
+        Ok(system
+            .accepted(component)
+            .iter()
+            .map(|acc| acc.combinations())
+            .sum())
+    }
+}
+
+fn read_workflows<'a>(lines: impl Iterator<Item = &'a str>) -> HashMap<String, Workflow> {
+    let mut workflows: HashMap<String, Workflow> = HashMap::new();
+    for line in lines {
+        if line.is_empty() {
+            break;
+        }
+        let line_preprocessed = line.replace('{', " ").replace('}', "");
+        let split: Vec<&str> = line_preprocessed.split_whitespace().collect();
+        let key = split[0].to_owned();
+        let workflow = Workflow::from(split[1]);
+        workflows.insert(key, workflow);
+    }
+    workflows
+}
+
+fn read_part(line: &str) -> [i64; 4] {
+    let mut part = [0; 4];
+    for rating in line.trim_matches(['{', '}']).split(',') {
+        let (characteristic, value) = rating.split_once('=').unwrap();
+        let index = match Xmas::from(characteristic.chars().next().unwrap()) {
+            Xmas::X => 0,
+            Xmas::M => 1,
+            Xmas::A => 2,
+            Xmas::S => 3,
+        };
+        part[index] = value.parse().unwrap();
+    }
+    part
+}
+
+#[derive(Hash, Debug)]
+struct Workflow {
+    rules: Vec<Rule>,
+}
+
+impl Workflow {
+    fn evaluate(&self, node: Node, global_q: &mut Vec<Node>) {
+        let mut component = node.component.clone();
+        for rule in self.rules.iter() {
+            if let Some(ref comparison) = rule.comparison {
+                //do comparison
+                let (passing, blocked) = comparison.compare_and_split(component);
+                if !passing.is_empty() {
+                    global_q.push(Node {
+                        component: passing,
+                        target: rule.target.clone(),
+                    });
+                }
+                if blocked.is_empty() {
+                    return;
+                }
+                component = blocked;
+            } else {
+                let node = Node {
+                    component: component.clone(),
+                    target: rule.target.clone(),
+                };
+                global_q.push(node);
+            }
+        }
+    }
+}
+
+impl From<&str> for Workflow {
+    fn from(s: &str) -> Self {
+        Self {
+            rules: s.split(',').map(Rule::from).collect(),
+        }
+    }
+}
+
+#[derive(Hash, Debug)]
+struct Rule {
+    comparison: Option<Comparison>,
+    target: Target,
+}
+
+impl From<&str> for Rule {
+    fn from(s: &str) -> Self {
+        if s.contains(':') {
+            let split: Vec<&str> = s.split(':').collect();
+            let s = split[0];
+            let key = split[1];
+            Self {
+                comparison: Some(Comparison::from(s)),
+                target: Target::from(key),
+            }
+        } else {
+            Self {
+                comparison: None,
+                target: Target::from(s),
+            }
+        }
+    }
+}
+
+#[derive(Hash, Debug)]
+struct Comparison {
+    characteristic: Xmas,
+    op: Op,
+    val: i64,
+}
+
+impl Comparison {
+    fn compare_and_split(&self, component: Component) -> (Component, Component) {
+        let comp_val = match self.characteristic {
+            Xmas::X => &component.x,
+            Xmas::M => &component.m,
+            Xmas::A => &component.a,
+            Xmas::S => &component.s,
+        };
+        let (passing_range, block_range) = match self.op {
+            Op::Lt => {
+                let split_at = self.val.clamp(comp_val.start, comp_val.end);
+                let passing_range = comp_val.start..split_at;
+                let block_range = split_at..comp_val.end;
+                (passing_range, block_range)
+            }
+            Op::Gt => {
+                let split_at = (self.val + 1).clamp(comp_val.start, comp_val.end);
+                let block_range = comp_val.start..split_at;
+                let passing_range = split_at..comp_val.end;
+                (passing_range, block_range)
+            }
+        };
+        match self.characteristic {
+            Xmas::X => (
+                Component {
+                    x: passing_range,
+                    ..component.clone()
+                },
+                Component {
+                    x: block_range,
+                    ..component.clone()
+                },
+            ),
+            Xmas::M => (
+                Component {
+                    m: passing_range,
+                    ..component.clone()
+                },
+                Component {
+                    m: block_range,
+                    ..component.clone()
+                },
+            ),
+            Xmas::A => (
+                Component {
+                    a: passing_range,
+                    ..component.clone()
+                },
+                Component {
+                    a: block_range,
+                    ..component.clone()
+                },
+            ),
+            Xmas::S => (
+                Component {
+                    s: passing_range,
+                    ..component.clone()
+                },
+                Component {
+                    s: block_range,
+                    ..component.clone()
+                },
+            ),
+        }
+    }
+}
+
+impl From<&str> for Comparison {
+    fn from(s: &str) -> Comparison {
+        let split: Vec<&str> = s.split(':').collect();
+        let s = split[0];
+        let mut chars = s.chars();
+        Self {
+            characteristic: Xmas::from(chars.next().unwrap()),
+            op: Op::from(chars.next().unwrap()),
+            val: chars.collect::<String>().parse().unwrap(),
+        }
+    }
+}
+
+#[derive(Hash, Debug)]
+enum Xmas {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl From<char> for Xmas {
+    fn from(c: char) -> Self {
+        match c {
+            'x' => Self::X,
+            'm' => Self::M,
+            'a' => Self::A,
+            's' => Self::S,
+            _ => panic!("invalid xmas character"),
+        }
+    }
+}
+
+#[derive(Hash, Debug)]
+enum Op {
+    Lt,
+    Gt,
+}
+
+impl From<char> for Op {
+    fn from(c: char) -> Self {
+        match c {
+            '<' => Self::Lt,
+            '>' => Self::Gt,
+            _ => panic!("Invalid comparison character"),
+        }
+    }
+}
+
+#[derive(Clone, Hash, Debug, Eq, PartialEq)]
+enum Target {
+    Rejected,
+    Accepted,
+    Workflow(String),
+}
+
+impl From<&str> for Target {
+    fn from(s: &str) -> Self {
+        match s {
+            "R" => Self::Rejected,
+            "A" => Self::Accepted,
+            &_ => Self::Workflow(s.to_owned()),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Node {
+    component: Component,
+    target: Target,
+}
+
+#[derive(Clone)]
+struct Component {
+    x: Range<i64>,
+    m: Range<i64>,
+    a: Range<i64>,
+    s: Range<i64>,
+}
+
+impl Component {
+    fn is_empty(&self) -> bool {
+        self.x.is_empty() || self.m.is_empty() || self.a.is_empty() || self.s.is_empty()
+    }
+
+    fn combinations(&self) -> i64 {
+        (self.x.end - self.x.start)
+            * (self.m.end - self.m.start)
+            * (self.a.end - self.a.start)
+            * (self.s.end - self.s.start)
+    }
+}
+
+#[test]
+fn test_answers() {
+    assert_answers::<Day19>("answers.txt");
+}
@@ -0,0 +1,418 @@
+use aoc_utils::input::{InputError, Source};
+#[cfg(test)]
+use aoc_utils::{answers::assert_answers, input::read_file};
+use aoc_utils::{csv, Solution};
+use std::collections::{BTreeMap, BTreeSet};
+use std::convert::Infallible;
+use std::fmt::{self, Write as _};
+use std::path::Path;
+
+/// How many cubes there are of each colour, by colour name.
+pub type Cubes = BTreeMap<String, usize>;
+
+/* Counts are written like "3 blue, 4 red". Colour names can be anything, even several words. */
+fn parse_cubes(s: &str) -> Option<Cubes> {
+    let mut cubes = Cubes::new();
+    for count in s
+        .split([',', '\n'])
+        .map(str::trim)
+        .filter(|c| !c.is_empty())
+    {
+        let (number, colour) = count.split_once(char::is_whitespace)?;
+        *cubes.entry(colour.trim().to_owned()).or_default() += number.parse::<usize>().ok()?;
+    }
+    Some(cubes)
+}
+
+#[derive(Debug)]
+pub enum BagError {
+    Read(InputError),
+    Invalid(String),
+}
+
+impl fmt::Display for BagError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Read(error) => write!(f, "{error}"),
+            Self::Invalid(text) => write!(
+                f,
+                "a bag should list its cubes like \"12 red, 13 green\", got {text:?}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BagError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Read(error) => Some(error),
+            Self::Invalid(_) => None,
+        }
+    }
+}
+
+/// The cubes in the bag the games were played with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bag {
+    pub cubes: Cubes,
+}
+
+impl Default for Bag {
+    /// The bag from the puzzle.
+    fn default() -> Self {
+        Self::parse("12 red, 13 green, 14 blue").unwrap()
+    }
+}
+
+impl Bag {
+    /// Reads a bag from text like "12 red, 13 green, 14 blue", separated by commas or newlines.
+    pub fn parse(text: &str) -> Result<Self, BagError> {
+        match parse_cubes(text) {
+            Some(cubes) => Ok(Self { cubes }),
+            None => Err(BagError::Invalid(text.trim().to_owned())),
+        }
+    }
+
+    pub fn read(source: &Source) -> Result<Self, BagError> {
+        Self::parse(&source.read_to_string().map_err(BagError::Read)?)
+    }
+
+    /// A command line argument, naming either a file to read the bag from or the bag itself.
+    pub fn from_arg(arg: &str) -> Result<Self, BagError> {
+        if Path::new(arg).is_file() {
+            Self::read(&Source::file(arg))
+        } else {
+            Self::parse(arg)
+        }
+    }
+
+    /// How many cubes of `colour` the bag holds, none for colours it doesn't know.
+    pub fn count(&self, colour: &str) -> usize {
+        self.cubes.get(colour).copied().unwrap_or(0)
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct Round {
+    cubes: Cubes,
+}
+
+impl Round {
+    fn from(s: &str) -> Self {
+        let cubes = parse_cubes(s).unwrap_or_else(|| panic!("unable to read a round from {s:?}"));
+        Self { cubes }
+    }
+
+    fn is_possible(&self, bag: &Bag) -> bool {
+        self.cubes
+            .iter()
+            .all(|(colour, &count)| count <= bag.count(colour))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Game {
+    id: usize,
+    rounds: Vec<Round>,
+}
+
+impl Game {
+    pub fn is_possible(&self, bag: &Bag) -> bool {
+        self.rounds.iter().all(|round| round.is_possible(bag))
+    }
+
+    /// Works out what the game says about the bag, without changing the game.
+    pub fn stats(&self, bag: &Bag) -> GameStats {
+        let mut maxima = Cubes::new();
+        let mut excesses = vec![];
+        for (i, round) in self.rounds.iter().enumerate() {
+            for (colour, &drawn) in &round.cubes {
+                let most = maxima.entry(colour.clone()).or_default();
+                *most = (*most).max(drawn);
+                if drawn > bag.count(colour) {
+                    excesses.push(Excess {
+                        round: i + 1,
+                        colour: colour.clone(),
+                        drawn,
+                        in_bag: bag.count(colour),
+                    });
+                }
+            }
+        }
+        let power = maxima.values().product();
+        GameStats {
+            id: self.id,
+            maxima,
+            power,
+            excesses,
+        }
+    }
+}
+
+/// A colour drawn in greater numbers in one round than the bag holds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Excess {
+    /// The round it happened in, counting from 1.
+    pub round: usize,
+    pub colour: String,
+    pub drawn: usize,
+    pub in_bag: usize,
+}
+
+impl fmt::Display for Excess {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "round {}: {} {} drawn, {} in the bag",
+            self.round, self.drawn, self.colour, self.in_bag
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameStats {
+    pub id: usize,
+    /// The most cubes of each colour drawn in any one round, which is the fewest the bag could
+    /// have held.
+    pub maxima: Cubes,
+    /// The product of the maxima, whatever the bag holds.
+    pub power: usize,
+    /// Every colour of every round that the bag couldn't have supplied.
+    pub excesses: Vec<Excess>,
+}
+
+impl GameStats {
+    pub fn is_possible(&self) -> bool {
+        self.excesses.is_empty()
+    }
+}
+
+pub fn game_stats(games: &[Game], bag: &Bag) -> Vec<GameStats> {
+    games.iter().map(|game| game.stats(bag)).collect()
+}
+
+/* The colour columns cover the bag and anything drawn in a game, so nothing goes unseen */
+fn colours<'a>(stats: &'a [GameStats], bag: &'a Bag) -> BTreeSet<&'a str> {
+    stats
+        .iter()
+        .flat_map(|game| game.maxima.keys())
+        .chain(bag.cubes.keys())
+        .map(String::as_str)
+        .collect()
+}
+
+/// A table of each game's maxima and power, explaining what made the impossible games impossible.
+pub fn explain(stats: &[GameStats], bag: &Bag) -> String {
+    let colours = colours(stats, bag);
+    let mut text = format!("game  possible  {:>8}", "power");
+    for colour in &colours {
+        write!(text, "  {colour:>5}").unwrap();
+    }
+    text.push_str("  why not\n");
+    for game in stats {
+        let possible = if game.is_possible() { "yes" } else { "no" };
+        write!(text, "{:>4}  {possible:<8}  {:>8}", game.id, game.power).unwrap();
+        for colour in &colours {
+            let most = game.maxima.get(*colour).copied().unwrap_or(0);
+            write!(text, "  {most:>width$}", width = colour.len().max(5)).unwrap();
+        }
+        for (i, excess) in game.excesses.iter().enumerate() {
+            let separator = if i == 0 { "  " } else { "; " };
+            write!(text, "{separator}{excess}").unwrap();
+        }
+        text.push('\n');
+    }
+    let possible: Vec<&GameStats> = stats.iter().filter(|game| game.is_possible()).collect();
+    writeln!(
+        text,
+        "{} of {} games possible, their ids summing to {}; total power {}",
+        possible.len(),
+        stats.len(),
+        possible.iter().map(|game| game.id).sum::<usize>(),
+        stats.iter().map(|game| game.power).sum::<usize>()
+    )
+    .unwrap();
+    text
+}
+
+/// The same as [`explain`] as CSV, with a column for each colour's maximum.
+pub fn explain_csv(stats: &[GameStats], bag: &Bag) -> String {
+    let colours = colours(stats, bag);
+    let mut rows = String::from("game,possible,power");
+    for colour in &colours {
+        write!(rows, ",{}", csv::field(colour)).unwrap();
+    }
+    rows.push_str(",why_not\n");
+    for game in stats {
+        write!(rows, "{},{},{}", game.id, game.is_possible(), game.power).unwrap();
+        for colour in &colours {
+            write!(rows, ",{}", game.maxima.get(*colour).copied().unwrap_or(0)).unwrap();
+        }
+        let reasons: Vec<String> = game.excesses.iter().map(Excess::to_string).collect();
+        writeln!(rows, ",{}", csv::field(&reasons.join("; "))).unwrap();
+    }
+    rows
+}
+
+/// The sum of the ids of the games which could have been played with `bag`.
+pub fn possible_games(games: &[Game], bag: &Bag) -> usize {
+    games
+        .iter()
+        .filter(|game| game.is_possible(bag))
+        .map(|game| game.id)
+        .sum()
+}
+
+/// The sum of every game's power.
+pub fn total_power(games: &[Game]) -> usize {
+    games
+        .iter()
+        .map(|game| game.stats(&Bag::default()).power)
+        .sum()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Game>;
+    type Answer = usize;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let mut games: Vec<Game> = vec![];
+        for input in input.lines() {
+            let game_rounds_split: Vec<&str> = input.split(':').collect();
+            games.push(Game {
+                id: game_rounds_split[0]
+                    .replace("Game", " ")
+                    .trim_start()
+                    .parse()
+                    .unwrap(),
+                rounds: {
+                    let rounds: Vec<&str> = game_rounds_split[1].split(';').collect();
+                    rounds.iter().map(|r| Round::from(r)).collect()
+                },
+            });
+        }
+        Ok(games)
+    }
+
+    fn part1(games: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(possible_games(games, &Bag::default()))
+    }
+
+    fn part2(games: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(total_power(games))
+    }
+}
+
+#[test]
+fn test_answers() {
+    assert_answers::<Day2>("answers.txt");
+}
+
+#[test]
+fn test_any_colour() {
+    let round = Round::from(" 3 blue, 4 light red, 1 blue");
+    assert_eq!(round.cubes["blue"], 4);
+    assert_eq!(round.cubes["light red"], 4);
+    assert_eq!(round.cubes.len(), 2);
+}
+
+#[test]
+fn test_bags() {
+    let games = Day2::parse(&read_file("small_input.txt")).unwrap();
+    assert_eq!(
+        Bag::parse("14 blue\n12 red\n13 green\n").unwrap(),
+        Bag::default()
+    );
+    assert_eq!(
+        possible_games(&games, &Bag::parse("20 red, 13 green, 15 blue").unwrap()),
+        15
+    );
+    /* a colour missing from the bag can't be drawn at all */
+    assert_eq!(
+        possible_games(&games, &Bag::parse("12 red, 13 green").unwrap()),
+        0
+    );
+    assert!(matches!(
+        Bag::parse("12 red, green"),
+        Err(BagError::Invalid(text)) if text == "12 red, green"
+    ));
+
+    let path = std::env::temp_dir().join(format!("day2_bag_{}.txt", std::process::id()));
+    std::fs::write(&path, "1 red\n2 green").unwrap();
+    let from_file = Bag::from_arg(path.to_str().unwrap());
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(from_file.unwrap(), Bag::parse("1 red, 2 green").unwrap());
+    assert_eq!(Bag::from_arg("5 red").unwrap().count("red"), 5);
+}
+
+#[test]
+fn test_stats() {
+    let games = Day2::parse(&read_file("small_input.txt")).unwrap();
+    let stats = game_stats(&games, &Bag::default());
+    assert_eq!(
+        stats.iter().map(|game| game.power).collect::<Vec<_>>(),
+        [48, 12, 1560, 630, 36]
+    );
+    /* the power only depends on what was drawn, not on what the bag holds */
+    let small_bag = Bag::parse("1 red").unwrap();
+    assert_eq!(
+        game_stats(&games, &small_bag)
+            .iter()
+            .map(|game| game.power)
+            .collect::<Vec<_>>(),
+        [48, 12, 1560, 630, 36]
+    );
+    assert_eq!(total_power(&games), 2286);
+    assert_eq!(
+        stats[2].maxima,
+        Bag::parse("20 red, 13 green, 6 blue").unwrap().cubes
+    );
+    assert!(stats[0].is_possible());
+    assert_eq!(
+        stats[2].excesses,
+        [Excess {
+            round: 1,
+            colour: "red".to_owned(),
+            drawn: 20,
+            in_bag: 12
+        }]
+    );
+    assert_eq!(
+        stats[3]
+            .excesses
+            .iter()
+            .map(Excess::to_string)
+            .collect::<Vec<_>>(),
+        [
+            "round 3: 15 blue drawn, 14 in the bag",
+            "round 3: 14 red drawn, 12 in the bag"
+        ]
+    );
+    /* working out the stats leaves the rounds as they were */
+    let before = games[3].rounds.clone();
+    games[3].stats(&Bag::default());
+    assert_eq!(games[3].rounds, before);
+}
+
+#[test]
+fn test_explain() {
+    let games = Day2::parse(&read_file("small_input.txt")).unwrap();
+    let bag = Bag::default();
+    let stats = game_stats(&games[1..3], &bag);
+    let expected = "\
+game  possible     power   blue  green    red  why not
+   2  yes             12      4      3      1
+   3  no            1560      6     13     20  round 1: 20 red drawn, 12 in the bag
+1 of 2 games possible, their ids summing to 2; total power 1572
+";
+    assert_eq!(explain(&stats, &bag), expected);
+    let expected_csv = "\
+game,possible,power,blue,green,red,why_not
+2,true,12,4,3,1,
+3,false,1560,6,13,20,\"round 1: 20 red drawn, 12 in the bag\"
+";
+    assert_eq!(explain_csv(&stats, &bag), expected_csv);
+}
@@ -0,0 +1,407 @@
+#[cfg(test)]
+use aoc_utils::answers::assert_answers;
+use aoc_utils::Solution;
+use num::integer::lcm;
+use std::collections::VecDeque;
+use std::{collections::HashMap, convert::From, fmt};
+
+pub struct Machine {
+    graph: Graph,
+    lookup: HashMap<String, usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MachineError {
+    NoRxFeeder,
+    GaveUp { presses: usize, silent: Vec<String> },
+}
+
+impl fmt::Display for MachineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoRxFeeder => write!(f, "rx has to be fed by a single conjunction for part 2"),
+            Self::GaveUp { presses, silent } => write!(
+                f,
+                "gave up after {presses} presses, {silent:?} never sent a high signal"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MachineError {}
+
+pub struct Day20;
+
+/* The periods in the puzzle are all a few thousand presses, so this leaves plenty of room */
+const MAX_PRESSES: usize = 1 << 16;
+
+impl Solution for Day20 {
+    type Input = Machine;
+    type Answer = usize;
+    type Error = MachineError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let (graph, lookup) = build_graph_and_lookup(input);
+        Ok(Machine { graph, lookup })
+    }
+
+    fn part1(machine: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        let mut graph = machine.graph.clone();
+        let mut lookup = machine.lookup.clone();
+        let mut high_signals: usize = 0;
+        let mut low_signals: usize = 0;
+        for _ in 1..=1000 {
+            press_button(
+                &mut lookup,
+                &mut graph,
+                &mut high_signals,
+                &mut low_signals,
+                &mut HashMap::new(),
+            );
+        }
+        Ok(high_signals * low_signals)
+    }
+
+    fn part2(machine: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        /* rx is fed by a single conjunction, which only sends a low signal once all of its own
+        inputs have sent a high one. Each of those inputs does so periodically, so the first
+        press that lines them all up is the LCM of their periods. */
+        let mut graph = machine.graph.clone();
+        let mut lookup = machine.lookup.clone();
+        let rx_feeder = machine
+            .graph
+            .adj_lists
+            .iter()
+            .position(|adj_list| adj_list.iter().any(|adj| adj == "rx"))
+            .map(|idx| &machine.graph.nodes[idx]);
+        let mut feeder_sources: HashMap<String, bool> = match rx_feeder {
+            Some(Node::Conjunct(c)) => c.froms.iter().map(|f| (f.clone(), false)).collect(),
+            _ => return Err(MachineError::NoRxFeeder),
+        };
+
+        let mut high_signals: usize = 0;
+        let mut low_signals: usize = 0;
+        let mut rx_period: usize = 1;
+        let mut presses: usize = 0;
+        while !feeder_sources.is_empty() {
+            if presses == MAX_PRESSES {
+                let mut silent: Vec<String> = feeder_sources.into_keys().collect();
+                silent.sort();
+                return Err(MachineError::GaveUp { presses, silent });
+            }
+            presses += 1;
+            press_button(
+                &mut lookup,
+                &mut graph,
+                &mut high_signals,
+                &mut low_signals,
+                &mut feeder_sources,
+            );
+            feeder_sources.retain(|_, sent_high| {
+                if *sent_high {
+                    rx_period = lcm(rx_period, presses);
+                }
+                !*sent_high
+            });
+        }
+        Ok(rx_period)
+    }
+}
+
+fn press_button(
+    lookup: &mut HashMap<String, usize>,
+    graph: &mut Graph,
+    high_signals: &mut usize,
+    low_signals: &mut usize,
+    feeder_sources: &mut HashMap<String, bool>,
+) {
+    let mut signal_queue: VecDeque<Signal> = VecDeque::from([Signal {
+        level: State::Low,
+        to: "broadcaster".to_owned(),
+        from: "button".to_owned(),
+    }]);
+
+    *low_signals += 1;
+    let feeder_keys: Vec<String> = feeder_sources.clone().into_keys().collect();
+    while let Some(signal) = signal_queue.pop_front() {
+        if signal.to == "rx" || signal.to == "output" {
+            continue;
+        }
+        let idx = lookup.get(&signal.to).unwrap();
+        let adj_list = &graph.adj_lists[*idx];
+        let from = signal.to.clone();
+        if let Some(new_level) = graph.nodes[*idx].generate_output_state(&signal) {
+            for adj in adj_list {
+                if feeder_keys.contains(&from) && new_level == State::High {
+                    feeder_sources.insert(from.clone(), true);
+                }
+                if new_level == State::High {
+                    *high_signals += 1;
+                } else {
+                    *low_signals += 1;
+                }
+
+                let new_signal = Signal {
+                    from: from.clone(),
+                    level: new_level,
+                    to: adj.clone(),
+                };
+
+                signal_queue.push_back(new_signal);
+            }
+        }
+    }
+}
+
+fn build_graph_and_lookup(input: &str) -> (Graph, HashMap<String, usize>) {
+    let mut graph = Graph::new();
+    let mut node_lookup: HashMap<String, usize> = HashMap::new();
+    graph.nodes.push(Node::Button);
+    graph.adj_lists.push(vec!["broadcaster".to_owned()]);
+    for line in input.lines() {
+        let split: Vec<&str> = line.split("->").collect();
+        let node = Node::from(split[0].trim());
+        let node_name = node.get_name();
+        graph.nodes.push(node);
+        node_lookup.insert(node_name, graph.nodes.len() - 1);
+        graph
+            .adj_lists
+            .push(split[1].split(',').map(|s| s.trim().to_owned()).collect());
+    }
+    for (adj_list, source_node) in graph.adj_lists.iter().zip(graph.nodes.clone().iter()) {
+        for adj in adj_list.iter() {
+            if let Some(target_node_index) = node_lookup.get(adj) {
+                let target_node = &mut graph.nodes[*target_node_index];
+                if let &mut Node::Conjunct(ref mut c) = target_node {
+                    let source_name = source_node.get_name();
+                    c.froms.push(source_name.to_owned());
+                    c.inputs.push(State::Low);
+                }
+            }
+        }
+    }
+    (graph, node_lookup)
+}
+
+#[derive(PartialEq, Eq, Default, Clone, Copy, Debug)]
+enum State {
+    High,
+    #[default]
+    Low,
+}
+
+impl State {
+    fn toggle(&mut self) {
+        if *self == Self::High {
+            *self = Self::Low;
+        } else {
+            *self = Self::High;
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Graph {
+    nodes: Vec<Node>,
+    adj_lists: Vec<Vec<String>>,
+}
+
+impl Graph {
+    fn new() -> Self {
+        Self {
+            nodes: vec![],
+            adj_lists: vec![],
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Node {
+    Button, //Not generateable
+    Broadcaster,
+    FlipFlop(FlipFlop),
+    Conjunct(Conjunct),
+}
+
+impl Node {
+    fn get_name(&self) -> String {
+        match self {
+            Self::Button => "button".to_owned(),
+            Self::Broadcaster => "broadcaster".to_owned(),
+            Self::FlipFlop(FlipFlop { key, .. }) | Self::Conjunct(Conjunct { key, .. }) => {
+                key.clone()
+            }
+        }
+    }
+
+    fn generate_output_state(&mut self, input_signal: &Signal) -> Option<State> {
+        match self {
+            Self::Button | Self::Broadcaster => Some(State::Low),
+            Self::FlipFlop(f) => f.process(input_signal),
+            Self::Conjunct(c) => c.process(input_signal),
+        }
+    }
+}
+
+impl From<&str> for Node {
+    fn from(s: &str) -> Self {
+        let split: Vec<&str> = s.split("->").collect();
+        let key = split[0].to_owned().replace(['%', '&'], "");
+        if split[0] == "broadcaster" {
+            Self::Broadcaster
+        } else if s.starts_with('%') {
+            Self::FlipFlop(FlipFlop {
+                key,
+                ..Default::default()
+            })
+        } else if s.starts_with('&') {
+            Self::Conjunct(Conjunct {
+                key,
+                ..Default::default()
+            })
+        } else {
+            panic!("AAAA Don't try to add output or rx here");
+        }
+    }
+}
+
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+struct FlipFlop {
+    key: String,
+    state: State,
+}
+
+impl FlipFlop {
+    fn process(&mut self, input_signal: &Signal) -> Option<State> {
+        if input_signal.level == State::Low {
+            self.state.toggle();
+            Some(self.state)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+struct Conjunct {
+    key: String,
+    inputs: Vec<State>,
+    froms: Vec<String>,
+}
+
+impl Conjunct {
+    fn process(&mut self, input_signal: &Signal) -> Option<State> {
+        if let Some(index) = self.froms.iter().position(|f| *f == input_signal.from) {
+            self.inputs[index] = input_signal.level;
+        } else {
+            self.froms.push(input_signal.from.clone());
+            self.inputs.push(input_signal.level)
+        }
+
+        if self.inputs.iter().all(|input| *input == State::High) {
+            Some(State::Low)
+        } else {
+            Some(State::High)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct Signal {
+    level: State,
+    to: String,
+    from: String,
+}
+
+#[test]
+fn conjunction_behaviour_inverter() {
+    let mut conj = Conjunct::default();
+    let input_signal = Signal {
+        level: State::Low,
+        ..Default::default()
+    };
+    assert_eq!(conj.process(&input_signal), Some(State::High));
+    assert_eq!(conj.process(&input_signal), Some(State::High));
+    let input_signal = Signal {
+        level: State::High,
+        ..Default::default()
+    };
+    assert_eq!(conj.process(&input_signal), Some(State::Low));
+    assert_eq!(conj.process(&input_signal), Some(State::Low));
+}
+
+#[test]
+fn conjunction_behaviour_and() {
+    let mut conj = Conjunct::default();
+    let mut input_signal1 = Signal {
+        from: "A".to_owned(),
+        level: State::Low,
+        ..Default::default()
+    };
+    let mut input_signal2 = Signal {
+        from: "B".to_owned(),
+        level: State::Low,
+        ..Default::default()
+    };
+
+    assert_eq!(conj.process(&input_signal1), Some(State::High));
+    assert_eq!(conj.process(&input_signal2), Some(State::High));
+    input_signal1.level = State::High;
+    assert_eq!(conj.process(&input_signal1), Some(State::High));
+    assert_eq!(conj.process(&input_signal2), Some(State::High));
+    input_signal2.level = State::High;
+    assert_eq!(conj.process(&input_signal1), Some(State::High));
+    assert_eq!(conj.process(&input_signal2), Some(State::Low));
+    input_signal2.level = State::Low;
+    assert_eq!(conj.process(&input_signal1), Some(State::Low));
+    assert_eq!(conj.process(&input_signal2), Some(State::High));
+    input_signal1.level = State::Low;
+    assert_eq!(conj.process(&input_signal1), Some(State::High));
+    assert_eq!(conj.process(&input_signal2), Some(State::High));
+}
+
+#[test]
+fn flipflop_behaviour() {
+    let mut ff = FlipFlop::default();
+    assert_eq!(ff.state, State::Low);
+    let input_signal = Signal {
+        level: State::Low,
+        ..Default::default()
+    };
+    ff.process(&input_signal);
+    assert_eq!(ff.state, State::High);
+    ff.process(&input_signal);
+    assert_eq!(ff.state, State::Low);
+    let input_signal = Signal {
+        level: State::High,
+        ..Default::default()
+    };
+    ff.process(&input_signal);
+    assert_eq!(ff.state, State::Low);
+    ff.process(&input_signal);
+    assert_eq!(ff.state, State::Low);
+}
+
+#[test]
+fn test_answers() {
+    assert_answers::<Day20>("answers.txt");
+}
+
+#[test]
+fn test_no_rx_feeder() {
+    let machine = Day20::parse("broadcaster -> a\n%a -> output").unwrap();
+    assert_eq!(Day20::part2(&machine), Err(MachineError::NoRxFeeder));
+}
+
+#[test]
+fn test_silent_feeder() {
+    /* c is only fed by the flip-flop a, which nothing ever pulses */
+    let machine = Day20::parse("broadcaster -> b\n%a -> c\n%b -> output\n&c -> rx").unwrap();
+    assert_eq!(
+        Day20::part2(&machine),
+        Err(MachineError::GaveUp {
+            presses: MAX_PRESSES,
+            silent: vec!["a".to_owned()]
+        })
+    );
+}
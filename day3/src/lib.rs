@@ -0,0 +1,119 @@
+#![allow(dead_code)]
+
+#[cfg(test)]
+use aoc_utils::answers::assert_answers;
+use aoc_utils::Solution;
+use regex::Regex;
+use std::convert::Infallible;
+use std::fmt;
+
+#[derive(Debug, Clone)]
+struct Part {
+    number: usize,
+    dimensions: Dimensions,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} in row {}, start: {}, end: {}",
+            self.number, self.dimensions.row, self.dimensions.start, self.dimensions.end
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Dimensions {
+    row: usize,
+    start: usize,
+    end: usize,
+}
+
+#[derive(Debug)]
+struct Symbol {
+    row: usize,
+    column: usize,
+    glyph: char,
+}
+
+impl Symbol {
+    fn is_adjacent_to(&self, part: &Part) -> bool {
+        (self.row as isize - part.dimensions.row as isize).abs() <= 1
+            && isize::try_from(part.dimensions.start).unwrap() - 1 <= self.column as isize
+            && self.column <= part.dimensions.end
+    }
+}
+
+pub struct Schematic {
+    parts: Vec<Part>,
+    symbols: Vec<Symbol>,
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Schematic;
+    type Answer = usize;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let re = Regex::new("[0-9]+").unwrap();
+        let mut symbols: Vec<Symbol> = vec![];
+        let mut parts: Vec<Part> = vec![];
+        for (row, input) in input.lines().enumerate() {
+            symbols.extend(
+                input
+                    .chars()
+                    .enumerate()
+                    .filter(|(_, c)| *c != '.' && !c.is_ascii_digit())
+                    .map(|(column, glyph)| Symbol { row, column, glyph }),
+            );
+
+            let mut i = 0;
+            while let Some(found) = re.find_at(input, i) {
+                parts.push(Part {
+                    number: found.as_str().parse().unwrap(),
+                    dimensions: Dimensions {
+                        row,
+                        start: found.start(),
+                        end: found.end(),
+                    },
+                });
+                i = found.end();
+            }
+        }
+        Ok(Schematic { parts, symbols })
+    }
+
+    fn part1(schematic: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(schematic
+            .parts
+            .iter()
+            .filter(|part| schematic.symbols.iter().any(|s| s.is_adjacent_to(part)))
+            .map(|part| part.number)
+            .sum())
+    }
+
+    fn part2(schematic: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(schematic
+            .symbols
+            .iter()
+            .filter(|s| s.glyph == '*')
+            .map(|gear| {
+                schematic
+                    .parts
+                    .iter()
+                    .filter(|part| gear.is_adjacent_to(part))
+                    .collect::<Vec<&Part>>()
+            })
+            .filter(|attached_parts| attached_parts.len() > 1)
+            .map(|attached_parts| attached_parts.iter().map(|p| p.number).product::<usize>())
+            .sum())
+    }
+}
+
+#[test]
+fn test_answers() {
+    assert_answers::<Day3>("answers.txt");
+}
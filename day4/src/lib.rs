@@ -0,0 +1,94 @@
+#[cfg(test)]
+use aoc_utils::answers::assert_answers;
+use aoc_utils::Solution;
+use std::collections::{HashMap, HashSet};
+use std::convert::Infallible;
+use std::iter::Iterator;
+
+pub struct Card {
+    id: usize,
+    num_matches: usize,
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Card>;
+    type Answer = usize;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(input
+            .lines()
+            .map(|line| {
+                let card_input: Vec<&str> = line.split(':').collect::<Vec<&str>>();
+                let id: usize = card_input[0]
+                    .replace("Card", "")
+                    .trim_start()
+                    .parse()
+                    .unwrap_or_else(|_| panic!("Unable to get game id from '{}'", card_input[0]));
+                let card_input: Vec<&str> = card_input[1].split('|').collect();
+                Card {
+                    id,
+                    num_matches: count_matches(&card_input),
+                }
+            })
+            .collect())
+    }
+
+    fn part1(cards: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(cards
+            .iter()
+            .filter(|card| card.num_matches > 0)
+            .map(|card| 1 << (card.num_matches - 1))
+            .sum())
+    }
+
+    fn part2(cards: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        let mut histogram: HashMap<usize, usize> = HashMap::new();
+        for card in cards {
+            let id = card.id;
+            *histogram.entry(id).or_insert(0) += 1;
+            let copy_ids = id + 1..=(id + card.num_matches);
+            for _ in 0..*histogram.get(&id).unwrap() {
+                for id in copy_ids.clone() {
+                    *histogram.entry(id).or_insert(0) += 1;
+                }
+            }
+        }
+        Ok(histogram.into_values().sum())
+    }
+}
+
+fn count_matches(card_input: &[&str]) -> usize {
+    let winning_vector: Vec<usize> = card_input[0]
+        .split_whitespace()
+        .map(|p| {
+            p.parse().unwrap_or_else(|_| {
+                panic!(
+                    "Unable to extract values from  '{}' in '{}'",
+                    p, card_input[0]
+                )
+            })
+        })
+        .collect();
+    let drawn_vector: Vec<usize> = card_input[1]
+        .split_whitespace()
+        .map(|p| {
+            p.parse().unwrap_or_else(|_| {
+                panic!(
+                    "Unable to extract values from '{}' in '{}'",
+                    p, card_input[1]
+                )
+            })
+        })
+        .collect();
+    let winning_numbers: HashSet<usize> = HashSet::from_iter(winning_vector.iter().cloned());
+    let drawn_numbers: HashSet<usize> = HashSet::from_iter(drawn_vector.iter().cloned());
+    winning_numbers.intersection(&drawn_numbers).count()
+}
+
+#[test]
+fn test_answers() {
+    assert_answers::<Day4>("answers.txt");
+}
@@ -0,0 +1,517 @@
+#[cfg(test)]
+use aoc_utils::{answers::assert_answers, input::read_file};
+use aoc_utils::{range_set::RangeSet, Solution};
+use std::collections::{HashMap, VecDeque};
+use std::{fmt, ops::Range};
+
+const SEED: &str = "seed";
+const LOCATION: &str = "location";
+
+/// How the numbers on the seeds line are read: one seed each (part 1), or `start length` pairs
+/// describing whole ranges of seeds (part 2).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeedMode {
+    Individual,
+    Pairs,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlmanacError {
+    MissingSeeds,
+    OddSeedCount(usize),
+    InvalidLine(String),
+    RangeOutsideMap(String),
+    DuplicateMap { source: String, destination: String },
+    UnknownCategory(String),
+    NoConversion { source: String, destination: String },
+    NotInvertible,
+}
+
+impl fmt::Display for AlmanacError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingSeeds => write!(f, "the almanac has no \"seeds:\" line with seeds on it"),
+            Self::OddSeedCount(count) => {
+                write!(
+                    f,
+                    "{count} seed numbers can't be read as start and length pairs"
+                )
+            }
+            Self::InvalidLine(line) => write!(f, "unable to parse almanac line {line:?}"),
+            Self::RangeOutsideMap(line) => {
+                write!(f, "range {line:?} appears before any map header")
+            }
+            Self::DuplicateMap {
+                source,
+                destination,
+            } => write!(f, "the {source}-to-{destination} map appears twice"),
+            Self::UnknownCategory(category) => {
+                write!(f, "no map converts to or from {category}")
+            }
+            Self::NoConversion {
+                source,
+                destination,
+            } => write!(f, "no chain of maps leads from {source} to {destination}"),
+            Self::NotInvertible => {
+                write!(f, "the maps send more than one seed to the same location")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AlmanacError {}
+
+#[derive(Debug, Clone)]
+struct FarmingRange {
+    source: Range<i64>,
+    conversion_offset: i64,
+}
+
+impl FarmingRange {
+    fn destination(&self) -> Range<i64> {
+        self.source.start + self.conversion_offset..self.source.end + self.conversion_offset
+    }
+
+    fn parse(line: &str) -> Result<Self, AlmanacError> {
+        let numbers: Vec<i64> = line
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<Result<_, _>>()
+            .map_err(|_| AlmanacError::InvalidLine(line.to_owned()))?;
+        let [destination, start, length] = numbers[..] else {
+            return Err(AlmanacError::InvalidLine(line.to_owned()));
+        };
+        Ok(Self {
+            source: start..start + length,
+            conversion_offset: destination - start,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FarmingMap {
+    ranges: Vec<FarmingRange>,
+}
+
+impl FarmingMap {
+    fn new() -> Self {
+        Self { ranges: vec![] }
+    }
+
+    /* Values covered by one of the map's ranges are moved by its offset, everything else
+    passes through unchanged */
+    pub fn convert(&self, values: &RangeSet<i64>) -> RangeSet<i64> {
+        let mut unmapped = values.clone();
+        let mut mapped = RangeSet::new();
+        for range in &self.ranges {
+            let source = RangeSet::from(range.source.clone());
+            mapped = mapped.union(
+                &unmapped
+                    .intersection(&source)
+                    .shift(range.conversion_offset),
+            );
+            unmapped = unmapped.difference(&source);
+        }
+        mapped.union(&unmapped)
+    }
+
+    pub fn apply(&self, value: i64) -> i64 {
+        self.ranges
+            .iter()
+            .find(|range| range.source.contains(&value))
+            .map_or(value, |range| value + range.conversion_offset)
+    }
+
+    fn sources(&self) -> RangeSet<i64> {
+        self.ranges
+            .iter()
+            .map(|range| range.source.clone())
+            .collect()
+    }
+
+    /* A single map doing the work of `self` followed by `next`. Every range of `self` is split
+    wherever its destination crosses into a different range of `next`, and the parts of `next`
+    which only ever see untouched values are carried over as they are. */
+    pub fn then(&self, next: &FarmingMap) -> FarmingMap {
+        let mut ranges = vec![];
+        for range in &self.ranges {
+            let offset = range.conversion_offset;
+            let mut unmapped = RangeSet::from(range.destination());
+            for next_range in &next.ranges {
+                let next_source = RangeSet::from(next_range.source.clone());
+                for hit in unmapped.intersection(&next_source).ranges() {
+                    ranges.push(FarmingRange {
+                        source: hit.start - offset..hit.end - offset,
+                        conversion_offset: offset + next_range.conversion_offset,
+                    });
+                }
+                unmapped = unmapped.difference(&next_source);
+            }
+            for miss in unmapped.ranges() {
+                ranges.push(FarmingRange {
+                    source: miss.start - offset..miss.end - offset,
+                    conversion_offset: offset,
+                });
+            }
+        }
+        let own_sources = self.sources();
+        for next_range in &next.ranges {
+            let untouched = RangeSet::from(next_range.source.clone()).difference(&own_sources);
+            for passed_through in untouched.ranges() {
+                ranges.push(FarmingRange {
+                    source: passed_through.clone(),
+                    conversion_offset: next_range.conversion_offset,
+                });
+            }
+        }
+        ranges.retain(|range| range.conversion_offset != 0);
+        ranges.sort_by_key(|range| range.source.start);
+        FarmingMap { ranges }
+    }
+
+    /* Only a map that shuffles values around without merging any has an inverse: the
+    destinations must not overlap, and must cover exactly the values the sources gave up */
+    pub fn inverse(&self) -> Option<FarmingMap> {
+        let destinations: RangeSet<i64> =
+            self.ranges.iter().map(FarmingRange::destination).collect();
+        let destination_len: i64 = self
+            .ranges
+            .iter()
+            .map(|r| r.source.end - r.source.start)
+            .sum();
+        let covered_len: i64 = destinations.ranges().iter().map(|r| r.end - r.start).sum();
+        if covered_len != destination_len || destinations != self.sources() {
+            return None;
+        }
+        let ranges = self
+            .ranges
+            .iter()
+            .map(|range| FarmingRange {
+                source: range.destination(),
+                conversion_offset: -range.conversion_offset,
+            })
+            .collect();
+        Some(FarmingMap { ranges })
+    }
+}
+
+struct AlmanacSection {
+    source: String,
+    destination: String,
+    map: FarmingMap,
+}
+
+pub struct Almanac {
+    seeds: Vec<i64>,
+    sections: Vec<AlmanacSection>,
+    /* composed once up front, or why it couldn't be */
+    seed_to_location: Result<FarmingMap, AlmanacError>,
+}
+
+impl Almanac {
+    /* Reads the seeds line and then any number of `<source>-to-<destination> map:` sections,
+    in whatever order and with whatever category names the file uses */
+    pub fn parse(input: &str) -> Result<Self, AlmanacError> {
+        let mut seeds = None;
+        let mut sections: Vec<AlmanacSection> = vec![];
+        for line in input.lines().filter(|line| !line.is_empty()) {
+            if let Some(seed_list) = line.strip_prefix("seeds:") {
+                seeds = Some(
+                    seed_list
+                        .split_whitespace()
+                        .map(str::parse)
+                        .collect::<Result<Vec<i64>, _>>()
+                        .map_err(|_| AlmanacError::InvalidLine(line.to_owned()))?,
+                );
+            } else if let Some(header) = line.strip_suffix(" map:") {
+                let Some((source, destination)) = header.split_once("-to-") else {
+                    return Err(AlmanacError::InvalidLine(line.to_owned()));
+                };
+                if sections
+                    .iter()
+                    .any(|s| s.source == source && s.destination == destination)
+                {
+                    return Err(AlmanacError::DuplicateMap {
+                        source: source.to_owned(),
+                        destination: destination.to_owned(),
+                    });
+                }
+                sections.push(AlmanacSection {
+                    source: source.to_owned(),
+                    destination: destination.to_owned(),
+                    map: FarmingMap::new(),
+                });
+            } else {
+                let Some(section) = sections.last_mut() else {
+                    return Err(AlmanacError::RangeOutsideMap(line.to_owned()));
+                };
+                section.map.ranges.push(FarmingRange::parse(line)?);
+            }
+        }
+        Ok(Almanac {
+            seeds: seeds.ok_or(AlmanacError::MissingSeeds)?,
+            seed_to_location: compose(&sections, SEED, LOCATION),
+            sections,
+        })
+    }
+
+    /// A single map converting `source` values to `destination` values, composed from the
+    /// shortest chain of sections linking the two categories.
+    pub fn conversion(&self, source: &str, destination: &str) -> Result<FarmingMap, AlmanacError> {
+        compose(&self.sections, source, destination)
+    }
+
+    pub fn seed_ranges(&self, mode: SeedMode) -> Result<RangeSet<i64>, AlmanacError> {
+        match mode {
+            SeedMode::Individual => Ok(self.seeds.iter().map(|seed| *seed..(seed + 1)).collect()),
+            SeedMode::Pairs => {
+                if !self.seeds.len().is_multiple_of(2) {
+                    return Err(AlmanacError::OddSeedCount(self.seeds.len()));
+                }
+                Ok(self
+                    .seeds
+                    .chunks(2)
+                    .map(|pair| pair[0]..(pair[0] + pair[1]))
+                    .collect())
+            }
+        }
+    }
+
+    /// The lowest location any of the seeds ends up at, with the seeds line read as `mode` says.
+    pub fn lowest_location(&self, mode: SeedMode) -> Result<i64, AlmanacError> {
+        let seeds = self.seed_ranges(mode)?;
+        self.seed_to_location()?
+            .convert(&seeds)
+            .min()
+            .ok_or(AlmanacError::MissingSeeds)
+    }
+
+    /// Every map in the almanac collapsed into one, straight from seed to location.
+    pub fn seed_to_location(&self) -> Result<&FarmingMap, AlmanacError> {
+        self.seed_to_location.as_ref().map_err(Clone::clone)
+    }
+
+    /// The seed which ends up at `location`, provided the almanac's maps can be run backwards.
+    pub fn seed_for_location(&self, location: i64) -> Result<i64, AlmanacError> {
+        let location_to_seed = self
+            .seed_to_location()?
+            .inverse()
+            .ok_or(AlmanacError::NotInvertible)?;
+        Ok(location_to_seed.apply(location))
+    }
+}
+
+fn compose(
+    sections: &[AlmanacSection],
+    source: &str,
+    destination: &str,
+) -> Result<FarmingMap, AlmanacError> {
+    for category in [source, destination] {
+        if !sections
+            .iter()
+            .any(|s| s.source == category || s.destination == category)
+        {
+            return Err(AlmanacError::UnknownCategory(category.to_owned()));
+        }
+    }
+
+    /* breadth first from the source, remembering the section used to reach each category */
+    let mut reached_by: HashMap<&str, Option<&AlmanacSection>> = HashMap::new();
+    reached_by.insert(source, None);
+    let mut queue = VecDeque::from([source]);
+    while let Some(category) = queue.pop_front() {
+        if category == destination {
+            break;
+        }
+        for section in sections.iter().filter(|s| s.source == category) {
+            if !reached_by.contains_key(section.destination.as_str()) {
+                reached_by.insert(&section.destination, Some(section));
+                queue.push_back(&section.destination);
+            }
+        }
+    }
+    if !reached_by.contains_key(destination) {
+        return Err(AlmanacError::NoConversion {
+            source: source.to_owned(),
+            destination: destination.to_owned(),
+        });
+    }
+
+    let mut chain = vec![];
+    let mut category = destination;
+    while let Some(section) = reached_by[category] {
+        chain.push(&section.map);
+        category = &section.source;
+    }
+    Ok(chain
+        .into_iter()
+        .rev()
+        .fold(FarmingMap::new(), |composed, map| composed.then(map)))
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Almanac;
+    type Answer = i64;
+    type Error = AlmanacError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let almanac = Almanac::parse(input)?;
+        almanac.seed_to_location()?;
+        Ok(almanac)
+    }
+
+    fn part1(almanac: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        almanac.lowest_location(SeedMode::Individual)
+    }
+
+    fn part2(almanac: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        almanac.lowest_location(SeedMode::Pairs)
+    }
+}
+
+#[test]
+fn seed_modes() {
+    let almanac = Day5::parse(&read_file("sample_input.txt")).unwrap();
+    assert_eq!(
+        almanac.seed_ranges(SeedMode::Individual).unwrap().ranges(),
+        &[13..15, 55..56, 79..80]
+    );
+    assert_eq!(
+        almanac.seed_ranges(SeedMode::Pairs).unwrap().ranges(),
+        &[55..68, 79..93]
+    );
+
+    let odd = Day5::parse(
+        &read_file("sample_input.txt").replace("seeds: 79 14 55 13", "seeds: 79 14 55"),
+    )
+    .unwrap();
+    assert_eq!(odd.lowest_location(SeedMode::Individual), Ok(43));
+    assert_eq!(
+        odd.lowest_location(SeedMode::Pairs),
+        Err(AlmanacError::OddSeedCount(3))
+    );
+}
+
+#[test]
+fn composed_map_matches_map_chain() {
+    let almanac = Day5::parse(&read_file("sample_input.txt")).unwrap();
+    let seed_to_location = almanac.seed_to_location().unwrap();
+    for seed in 0..120 {
+        let chained = almanac
+            .sections
+            .iter()
+            .fold(seed, |value, section| section.map.apply(value));
+        assert_eq!(seed_to_location.apply(seed), chained, "seed {seed}");
+    }
+}
+
+#[test]
+fn inverse_map_finds_seeds() {
+    let almanac = Day5::parse(&read_file("sample_input.txt")).unwrap();
+    assert_eq!(almanac.seed_for_location(82), Ok(79));
+    assert_eq!(almanac.seed_for_location(35), Ok(13));
+    assert_eq!(almanac.seed_for_location(46), Ok(82));
+    let seed_to_location = almanac.seed_to_location().unwrap();
+    let location_to_seed = seed_to_location.inverse().unwrap();
+    for location in 0..120 {
+        let seed = location_to_seed.apply(location);
+        assert_eq!(seed_to_location.apply(seed), location);
+    }
+
+    let merging = Almanac::parse("seeds: 1\nseed-to-location map:\n0 10 5\n").unwrap();
+    assert_eq!(
+        merging.seed_for_location(2),
+        Err(AlmanacError::NotInvertible)
+    );
+}
+
+#[test]
+fn merging_map_has_no_inverse() {
+    let map = FarmingMap {
+        ranges: vec![FarmingRange::parse("0 10 5").unwrap()],
+    };
+    assert!(map.inverse().is_none());
+}
+
+#[test]
+fn conversion_between_any_categories() {
+    let almanac = Day5::parse(&read_file("sample_input.txt")).unwrap();
+    /* seed 79 has soil 81, fertilizer 81, water 81, light 74, temperature 78, humidity 78 */
+    let soil_to_humidity = almanac.conversion("soil", "humidity").unwrap();
+    assert_eq!(soil_to_humidity.apply(81), 78);
+    let light_to_light = almanac.conversion("light", "light").unwrap();
+    assert_eq!(light_to_light.apply(74), 74);
+    assert_eq!(
+        almanac.conversion("humidity", "soil").unwrap_err(),
+        AlmanacError::NoConversion {
+            source: "humidity".to_owned(),
+            destination: "soil".to_owned()
+        }
+    );
+    assert_eq!(
+        almanac.conversion("seed", "weather").unwrap_err(),
+        AlmanacError::UnknownCategory("weather".to_owned())
+    );
+}
+
+#[test]
+fn sections_in_any_order() {
+    let input = "seeds: 1 5\n\nsoil-to-location map:\n100 0 10\n\nseed-to-soil map:\n0 1 1\n";
+    let almanac = Day5::parse(input).unwrap();
+    let seed_to_location = almanac.seed_to_location().unwrap();
+    assert_eq!(seed_to_location.apply(1), 100);
+    assert_eq!(seed_to_location.apply(5), 105);
+}
+
+#[test]
+fn broken_almanacs_are_rejected() {
+    assert_eq!(
+        Almanac::parse("seeds: 1\n10 20 30\n").err(),
+        Some(AlmanacError::RangeOutsideMap("10 20 30".to_owned()))
+    );
+    assert_eq!(
+        Almanac::parse("seeds: 1\nsoil map:\n").err(),
+        Some(AlmanacError::InvalidLine("soil map:".to_owned()))
+    );
+    assert_eq!(
+        Almanac::parse("seed-to-soil map:\n1 2 3\n").err(),
+        Some(AlmanacError::MissingSeeds)
+    );
+    let no_seeds = Almanac::parse("seeds:\nseed-to-location map:\n1 2 3\n").unwrap();
+    assert_eq!(
+        no_seeds.lowest_location(SeedMode::Individual),
+        Err(AlmanacError::MissingSeeds)
+    );
+    assert_eq!(
+        Almanac::parse("seeds: 1\na-to-b map:\na-to-b map:\n").err(),
+        Some(AlmanacError::DuplicateMap {
+            source: "a".to_owned(),
+            destination: "b".to_owned()
+        })
+    );
+    let no_location = Almanac::parse("seeds: 1\nseed-to-soil map:\n1 2 3\n").unwrap();
+    assert_eq!(
+        no_location.conversion(SEED, LOCATION).err(),
+        Some(AlmanacError::UnknownCategory("location".to_owned()))
+    );
+    assert_eq!(
+        no_location.seed_to_location().err(),
+        Some(AlmanacError::UnknownCategory("location".to_owned()))
+    );
+    assert_eq!(
+        no_location.seed_for_location(1),
+        Err(AlmanacError::UnknownCategory("location".to_owned()))
+    );
+    /* the puzzle needs the seeds to end up somewhere */
+    assert_eq!(
+        Day5::parse("seeds: 1 2 3").err(),
+        Some(AlmanacError::UnknownCategory("seed".to_owned()))
+    );
+}
+
+#[test]
+fn test_answers() {
+    assert_answers::<Day5>("answers.txt");
+}
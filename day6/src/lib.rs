@@ -0,0 +1,112 @@
+#[cfg(test)]
+use aoc_utils::answers::assert_answers;
+use aoc_utils::Solution;
+use std::convert::Infallible;
+
+#[derive(Debug)]
+enum Tag {
+    Time,
+    Distance,
+}
+
+impl Tag {
+    fn from_str(s: &str) -> Self {
+        match s {
+            "Time" => Self::Time,
+            "Distance" => Self::Distance,
+            &_ => panic!("Unexpected str type: {s}"),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Race {
+    time: usize,
+    distance: usize,
+}
+
+impl Race {
+    fn calc_num_win_conditions(&self) -> usize {
+        /*
+            (time - x) * x > distance
+            x = 1 -> 6 * 1 = 6 < d
+            x = 2 -> 5 * 2 = 10 >d
+            ...
+            x = 3 => 3 * 4 = 12
+            x = 4 => 4 * 3 = 12
+            ..
+        i.e we have symmetry around the middle and only have to calculate to halfway.
+        x = 1..=(time / 2), then, if odd, double the solutions. if even: double - 1
+
+        */
+        let mut counter = 0;
+        for x in 1..=(self.time / 2) {
+            if (self.time - x) * x > self.distance {
+                counter += 1;
+            }
+        }
+        counter *= 2;
+        if self.time.is_multiple_of(2) {
+            counter -= 1;
+        }
+        counter
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<Race>;
+    type Answer = usize;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let mut times: Vec<usize> = vec![];
+        let mut distances: Vec<usize> = vec![];
+        for line in input.lines() {
+            let split: Vec<&str> = line.split(':').collect();
+            let tag = split[0];
+            let parts = split[1]
+                .split_whitespace()
+                .map(|p| p.parse::<usize>().unwrap());
+            match Tag::from_str(tag) {
+                Tag::Time => times.extend(parts),
+                Tag::Distance => distances.extend(parts),
+            }
+        }
+        Ok(times
+            .into_iter()
+            .zip(distances)
+            .map(|(time, distance)| Race { time, distance })
+            .collect())
+    }
+
+    fn part1(races: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(races
+            .iter()
+            .map(|race| race.calc_num_win_conditions())
+            .product())
+    }
+
+    fn part2(races: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        /* The kerning was a lie: all the numbers on a line are really one long number */
+        let join = |field: fn(&Race) -> usize| -> usize {
+            races
+                .iter()
+                .map(|race| field(race).to_string())
+                .collect::<String>()
+                .parse()
+                .unwrap()
+        };
+        let race = Race {
+            time: join(|race| race.time),
+            distance: join(|race| race.distance),
+        };
+        Ok(race.calc_num_win_conditions())
+    }
+}
+
+#[test]
+fn test_answers() {
+    assert_answers::<Day6>("answers.txt");
+}
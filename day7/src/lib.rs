@@ -0,0 +1,197 @@
+use aoc_utils::Solution;
+#[cfg(test)]
+use aoc_utils::{answers::assert_answers, input::read_file};
+use std::convert::Infallible;
+use std::{cmp::Ordering, collections::HashMap};
+
+#[derive(Debug, PartialEq, PartialOrd, Eq, Hash, Clone, Copy, Ord)]
+enum Card {
+    Ace,
+    King,
+    Queen,
+    Jack,
+    Ten,
+    Nine,
+    Eight,
+    Seven,
+    Six,
+    Five,
+    Four,
+    Three,
+    Two,
+    Joker,
+}
+
+impl Card {
+    fn from_char(c: &char, jokers: bool) -> Self {
+        match c {
+            'A' => Self::Ace,
+            'K' => Self::King,
+            'Q' => Self::Queen,
+            'T' => Self::Ten,
+            '9' => Self::Nine,
+            '8' => Self::Eight,
+            '7' => Self::Seven,
+            '6' => Self::Six,
+            '5' => Self::Five,
+            '4' => Self::Four,
+            '3' => Self::Three,
+            '2' => Self::Two,
+            'J' if jokers => Self::Joker,
+            'J' => Self::Jack,
+            &_ => panic!("Not a valid card! {c}"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Clone, Copy)]
+enum Hand {
+    FiveOfAKind,
+    FourOfAKind,
+    FullHouse,
+    ThreeOfAKind,
+    TwoPair,
+    OnePair,
+    HighCard,
+}
+
+impl Hand {
+    fn from_cards(cards: &Vec<Card>) -> Self {
+        let mut card_map = HashMap::<Card, usize>::new();
+        for card in cards {
+            *card_map.entry(*card).or_insert(0) += 1;
+        }
+        let num_jokers = if let Some(entry) = card_map.remove_entry(&Card::Joker) {
+            entry.1
+        } else {
+            0
+        };
+        let mut values: Vec<usize> = card_map.into_values().collect();
+        values.sort();
+        let values: Vec<usize> = values.into_iter().rev().collect();
+        let max_occuring_card = *values.first().unwrap_or(&0);
+
+        if max_occuring_card + num_jokers >= 5 {
+            Self::FiveOfAKind
+        } else if max_occuring_card + num_jokers >= 4 {
+            Self::FourOfAKind
+        } else if max_occuring_card + num_jokers >= 3 {
+            if values[1..].iter().any(|count| *count >= 2) {
+                Self::FullHouse
+            } else {
+                Self::ThreeOfAKind
+            }
+        } else if max_occuring_card + num_jokers >= 2 {
+            let num_jokers = if max_occuring_card == 1 {
+                num_jokers - 1
+            } else {
+                num_jokers
+            };
+            if values[1] + num_jokers == 2 {
+                Self::TwoPair
+            } else {
+                Self::OnePair
+            }
+        } else {
+            Self::HighCard
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Cards(Vec<Card>);
+
+#[derive(Debug, Clone)]
+pub struct Player {
+    hand: Hand,
+    bet: usize,
+    cards: Cards,
+}
+
+impl Player {
+    fn with_jokers(&self) -> Self {
+        let cards: Vec<Card> = self
+            .cards
+            .0
+            .iter()
+            .map(|card| match card {
+                Card::Jack => Card::Joker,
+                _ => *card,
+            })
+            .collect();
+        Self {
+            hand: Hand::from_cards(&cards),
+            bet: self.bet,
+            cards: Cards(cards),
+        }
+    }
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<Player>;
+    type Answer = usize;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let mut players: Vec<Player> = vec![];
+        for line in input.lines() {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            let cards: Vec<Card> = parts[0]
+                .chars()
+                .map(|c| Card::from_char(&c, false))
+                .collect();
+            players.push(Player {
+                hand: Hand::from_cards(&cards),
+                bet: parts[1].parse().unwrap(),
+                cards: Cards(cards),
+            })
+        }
+        Ok(players)
+    }
+
+    fn part1(players: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(total_winnings(players.clone()))
+    }
+
+    fn part2(players: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(total_winnings(
+            players.iter().map(|p| p.with_jokers()).collect(),
+        ))
+    }
+}
+
+fn total_winnings(mut players: Vec<Player>) -> usize {
+    players.sort_by(|a, b| {
+        let result = a.hand.cmp(&b.hand);
+        match result {
+            Ordering::Equal => a.cards.0.cmp(&b.cards.0),
+            _ => result,
+        }
+    });
+    let players: Vec<Player> = players.into_iter().rev().collect();
+    players
+        .iter()
+        .enumerate()
+        .map(|(i, p)| p.bet * (i + 1))
+        .sum()
+}
+
+#[test]
+fn test_num_players_sample() {
+    let players = Day7::parse(&read_file("sample_input.txt")).unwrap();
+    assert_eq!(players.len(), 5);
+}
+
+#[test]
+#[ignore = "needs the puzzle input.txt, which is not checked in"]
+fn test_num_players() {
+    let players = Day7::parse(&read_file("input.txt")).unwrap();
+    assert_eq!(players.len(), 1000);
+}
+
+#[test]
+fn test_answers() {
+    assert_answers::<Day7>("answers.txt");
+}
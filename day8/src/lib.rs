@@ -0,0 +1,150 @@
+use aoc_utils::Solution;
+#[cfg(test)]
+use aoc_utils::{answers::assert_answers, input::read_file};
+use num::integer::lcm;
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Debug)]
+pub enum Direction {
+    Right,
+    Left,
+}
+
+impl Direction {
+    fn from_char(c: char) -> Result<Self, NetworkError> {
+        match c {
+            'R' => Ok(Self::Right),
+            'L' => Ok(Self::Left),
+            _ => Err(NetworkError::InvalidDirection(c)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NetworkError {
+    NoDirections,
+    InvalidDirection(char),
+    InvalidLine(String),
+    UnknownNode(String),
+}
+
+impl fmt::Display for NetworkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoDirections => write!(f, "the network has no line of directions to follow"),
+            Self::InvalidDirection(c) => write!(f, "invalid direction {c:?}, expected L or R"),
+            Self::InvalidLine(line) => write!(f, "unable to parse network line {line:?}"),
+            Self::UnknownNode(node) => write!(f, "the network has no node {node}"),
+        }
+    }
+}
+
+impl std::error::Error for NetworkError {}
+
+pub struct Network {
+    directions: Vec<Direction>,
+    map: HashMap<String, (String, String)>,
+}
+
+impl Network {
+    fn steps_until(&self, start: &str, is_end: impl Fn(&str) -> bool) -> Result<i64, NetworkError> {
+        let mut circular = self.directions.iter().cycle();
+        let mut loop_loc = start.to_owned();
+        let mut counter: i64 = 0;
+        while !is_end(&loop_loc) {
+            let choices = self
+                .map
+                .get(&loop_loc)
+                .ok_or_else(|| NetworkError::UnknownNode(loop_loc.clone()))?;
+            loop_loc = match &circular.next().unwrap() {
+                Direction::Right => choices.1.clone(),
+                Direction::Left => choices.0.clone(),
+            };
+            counter += 1;
+        }
+        Ok(counter)
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Network;
+    type Answer = i64;
+    type Error = NetworkError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let mut lines = input.lines();
+        let directions = lines
+            .next()
+            .filter(|line| !line.is_empty())
+            .ok_or(NetworkError::NoDirections)?
+            .chars()
+            .map(Direction::from_char)
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut map: HashMap<String, (String, String)> = HashMap::new();
+        for line in lines {
+            if !line.is_empty() {
+                let invalid = || NetworkError::InvalidLine(line.to_owned());
+                let (key, value) = line.split_once('=').ok_or_else(invalid)?;
+                let value: String = value.replace(['(', ')', ','], "");
+                let value_vec: Vec<String> =
+                    value.split_whitespace().map(|s| s.to_owned()).collect();
+                let [left, right] = &value_vec[..] else {
+                    return Err(invalid());
+                };
+                map.insert(key.trim().to_owned(), (left.clone(), right.clone()));
+            }
+        }
+        Ok(Network { directions, map })
+    }
+
+    fn part1(network: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        network.steps_until("AAA", |loc| loc == "ZZZ")
+    }
+
+    fn part2(network: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        let current_locations: Vec<&String> =
+            network.map.keys().filter(|k| k.ends_with('A')).collect();
+        /*
+        A helpful comment on reddit mentioned LCMs, mainly because of the fact that
+        1. 6 various start points will at (usually different times) end in Z, but since they're not going to be ZZZ, they can jump anywhere
+        2. i.e. every start point has a period and will reach a thing ending in Z after a certain number of steps before looping.
+        3. So taking the LCM of the 6 start points will find the smallest number of cycles that will result in all the periods matching up
+        */
+        std::thread::scope(|scope| {
+            current_locations
+                .into_iter()
+                .map(|loc| scope.spawn(move || network.steps_until(loc, |loc| loc.ends_with('Z'))))
+                .collect::<Vec<_>>()
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .try_fold(1, |acc, steps| Ok(lcm(acc, steps?)))
+        })
+    }
+}
+
+#[test]
+fn test_missing_nodes() {
+    /* the ghosts' network has no AAA, so only part 2 can be answered */
+    let network = Day8::parse(&read_file("sample_input3.txt")).unwrap();
+    assert_eq!(
+        Day8::part1(&network).unwrap_err(),
+        NetworkError::UnknownNode("AAA".to_owned())
+    );
+    assert_eq!(Day8::part2(&network).unwrap(), 6);
+    assert_eq!(
+        Day8::parse("LR\n\nAAA = (BBB)").err(),
+        Some(NetworkError::InvalidLine("AAA = (BBB)".to_owned()))
+    );
+    assert_eq!(
+        Day8::parse("LUR").err(),
+        Some(NetworkError::InvalidDirection('U'))
+    );
+}
+
+#[test]
+fn test_answers() {
+    assert_answers::<Day8>("answers.txt");
+}
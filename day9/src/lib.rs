@@ -0,0 +1,65 @@
+#[cfg(test)]
+use aoc_utils::answers::assert_answers;
+use aoc_utils::Solution;
+use std::convert::Infallible;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Vec<i64>>;
+    type Answer = i64;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(input
+            .lines()
+            .map(|line| {
+                line.split_whitespace()
+                    .map(|x| x.parse().unwrap())
+                    .collect()
+            })
+            .collect())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        Ok(input
+            .iter()
+            .map(|history| {
+                difference_histories(history)
+                    .iter()
+                    .map(|history| history.last().unwrap())
+                    .sum::<i64>()
+            })
+            .sum())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer, Self::Error> {
+        let mut sums: i64 = 0;
+        for history in input {
+            let histories = difference_histories(history);
+            sums += histories[0][0]
+                - histories[1..]
+                    .iter()
+                    .enumerate()
+                    .map(|(i, history)| history.iter().next().unwrap() * ((-1_i64).pow((i) as u32)))
+                    .reduce(|acc, x| acc + x)
+                    .unwrap();
+        }
+        Ok(sums)
+    }
+}
+
+fn difference_histories(history: &[i64]) -> Vec<Vec<i64>> {
+    let mut history = history.to_vec();
+    let mut histories: Vec<Vec<i64>> = vec![history.clone()];
+    while !history.iter().all(|x| *x == 0) {
+        history = history.windows(2).map(|sl| sl[1] - sl[0]).collect();
+        histories.push(history.clone());
+    }
+    histories
+}
+
+#[test]
+fn test_answers() {
+    assert_answers::<Day9>("answers.txt");
+}